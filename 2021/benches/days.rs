@@ -0,0 +1,49 @@
+//! Parse and part timings of every day on its real input.
+//!
+//! Days without an input in the inputs directory are skipped.
+use aoc_2021::days::*;
+use aoc_2021::{input, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solution>(c: &mut Criterion) {
+    let input = match input::from_dir(S::DAY) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Day {}: {}, skipping", S::DAY, err);
+            return;
+        }
+    };
+    let parsed = match S::parse(&input) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("Day {}: cannot parse input: {}, skipping", S::DAY, err);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+macro_rules! bench_days {
+    ($($day:ident),+) => {
+        fn days(c: &mut Criterion) {
+            $(bench_day::<$day>(c);)+
+        }
+    };
+}
+
+bench_days!(
+    Day01, Day02, Day03, Day04, Day05, Day06, Day07, Day08, Day09, Day10, Day11, Day12, Day13,
+    Day14, Day15, Day16, Day17, Day18, Day19, Day20, Day21, Day22, Day23, Day24, Day25
+);
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1 @@
+aoc_2021::main!(aoc_2021::days::Day22);
@@ -0,0 +1,34 @@
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[inline]
+fn count_increases(xs: &[i64]) -> usize {
+    xs.windows(2).filter(|&slice| slice[1] > slice[0]).count()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input<'a> = Vec<i64>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.lines().map(|s| parse_at(input, s)).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(count_increases(input))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(count_increases(
+            &input
+                .windows(3)
+                .map(|slice| slice.iter().sum())
+                .collect::<Vec<_>>(),
+        ))
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day02-command.pest"]
+pub struct CommandParser;
+
+#[derive(Debug)]
+pub enum Command {
+    Forward(usize),
+    Down(usize),
+    Up(usize),
+}
+
+#[derive(Default, Debug)]
+struct State {
+    aim: usize,
+    position: usize,
+    depth: usize,
+}
+
+impl State {
+    fn execute_simple(&mut self, command: &Command) {
+        match command {
+            Command::Forward(q) => {
+                self.position += q;
+            }
+            Command::Down(q) => {
+                self.depth += q;
+            }
+            Command::Up(q) => {
+                self.depth -= q;
+            }
+        }
+    }
+
+    fn execute_aimed(&mut self, command: &Command) {
+        match command {
+            Command::Forward(q) => {
+                self.position += q;
+                self.depth += self.aim * q
+            }
+            Command::Down(q) => {
+                self.aim += q;
+            }
+            Command::Up(q) => {
+                self.aim -= q;
+            }
+        }
+    }
+}
+
+fn parse_line(input: &str, s: &str) -> Result<Command, ParseError> {
+    let parsed = CommandParser::parse(Rule::line, s)
+        .map_err(|err| ParseError::from(err).within(input, s))?
+        .next()
+        .unwrap();
+
+    let rules: Vec<_> = parsed.into_inner().collect();
+    let quant = parse_at(input, rules[1].as_str())?;
+    Ok(match rules[0].as_str() {
+        "forward" => Command::Forward(quant),
+        "up" => Command::Up(quant),
+        "down" => Command::Down(quant),
+        _ => unreachable!(),
+    })
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input<'a> = Vec<Command>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input.lines().map(|s| parse_line(input, s)).collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let mut state = State::default();
+        input
+            .iter()
+            .for_each(|command| state.execute_simple(command));
+        Ok(state.depth * state.position)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let mut state = State::default();
+        input
+            .iter()
+            .for_each(|command| state.execute_aimed(command));
+        Ok(state.depth * state.position)
+    }
+}
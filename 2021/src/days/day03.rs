@@ -0,0 +1,163 @@
+use std::cmp::Ordering;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+trait Commonality {
+    fn oxy(&self) -> char;
+    fn scrubber(&self) -> char;
+}
+
+impl Commonality for Ordering {
+    fn oxy(&self) -> char {
+        match self {
+            Ordering::Greater | Ordering::Equal => '1',
+            Ordering::Less => '0',
+        }
+    }
+
+    fn scrubber(&self) -> char {
+        match self {
+            Ordering::Greater | Ordering::Equal => '0',
+            Ordering::Less => '1',
+        }
+    }
+}
+
+fn most_common(xs: &[&str], pos: &usize) -> Ordering {
+    let mut one_count = 0;
+    let mut zero_count = 0;
+    xs.iter().for_each(|&x| {
+        let digit = x.chars().nth(*pos).unwrap();
+        match digit.eq(&'1') {
+            true => one_count += 1,
+            false => zero_count += 1,
+        }
+    });
+    one_count.cmp(&zero_count)
+}
+
+/// `x` holds only binary digits and fits a `usize`, as `parse` checks.
+fn bin_to_int(x: &str) -> usize {
+    x.bytes()
+        .fold(0, |acc, digit| acc << 1 | (digit - b'0') as usize)
+}
+
+fn product(a: usize, b: usize) -> Result<usize, SolveError> {
+    a.checked_mul(b)
+        .ok_or(SolveError::new("the product doesn't fit in 64 bits"))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input<'a> = Vec<&'a str>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines: Vec<_> = input.lines().collect();
+        let width = match lines.first() {
+            Some(line) => line.len(),
+            None => return Err(ParseError::new(1, 1, "empty input")),
+        };
+        if width == 0 || width > usize::BITS as usize {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("expected 1 to {} digits per line", usize::BITS),
+            ));
+        }
+        for line in &lines {
+            if let Some(idx) = line.find(|c| c != '0' && c != '1') {
+                return Err(ParseError::at(
+                    input,
+                    &line[idx..],
+                    "expected a binary digit",
+                ));
+            }
+            if line.len() != width {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    format!("expected {} digits", width),
+                ));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let num_len = lines[0].len();
+        let mut sums = vec![0; num_len];
+        let half = (lines.len() / 2) as u16;
+
+        lines.iter().for_each(|&x| {
+            for (i, c) in x.chars().enumerate() {
+                let add = c as u8 - b'0';
+                sums[i] += add as u16;
+            }
+        });
+
+        let gamma_str: String = sums
+            .iter()
+            .map(|&x| if x > half { "1" } else { "0" })
+            .collect();
+
+        let epsilon_str: String = gamma_str
+            .chars()
+            .map(|x| if x == '0' { "1" } else { "0" })
+            .collect();
+
+        let gamma = bin_to_int(&gamma_str);
+        let epsilon = bin_to_int(&epsilon_str);
+        product(gamma, epsilon)
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let mut oxy_numbers = lines.clone();
+        let mut scrubber_numbers = lines.clone();
+
+        let mut pos = 0;
+        while oxy_numbers.len() > 1 {
+            if pos == lines[0].len() {
+                return Err(SolveError::new("duplicate numbers, no single rating left"));
+            }
+            let haystack = most_common(&oxy_numbers, &pos).oxy();
+            let new_numbers: Vec<_> = oxy_numbers
+                .iter()
+                .filter(|x| x.chars().nth(pos).unwrap() == haystack)
+                .copied()
+                .collect();
+
+            // when every number left shares this bit, none has the rarer one
+            if !new_numbers.is_empty() {
+                oxy_numbers = new_numbers;
+            }
+            pos += 1;
+        }
+
+        let mut pos = 0;
+        while scrubber_numbers.len() > 1 {
+            if pos == lines[0].len() {
+                return Err(SolveError::new("duplicate numbers, no single rating left"));
+            }
+            let haystack = most_common(&scrubber_numbers, &pos).scrubber();
+            let new_numbers: Vec<_> = scrubber_numbers
+                .iter()
+                .filter(|x| x.chars().nth(pos).unwrap() == haystack)
+                .copied()
+                .collect();
+
+            // when every number left shares this bit, none has the rarer one
+            if !new_numbers.is_empty() {
+                scrubber_numbers = new_numbers;
+            }
+            pos += 1;
+        }
+
+        product(bin_to_int(oxy_numbers[0]), bin_to_int(scrubber_numbers[0]))
+    }
+}
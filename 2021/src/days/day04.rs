@@ -0,0 +1,145 @@
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day04-bingo.pest"]
+pub struct BingoParser;
+
+fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<Table>), ParseError> {
+    let (draws_line, bingo_tables) = input.split_once('\n').unwrap_or((input, ""));
+    let bingo_tables = bingo_tables.trim_matches('\n');
+
+    let draws_parse = BingoParser::parse(Rule::draws, draws_line)?.next().unwrap();
+
+    let bingo_parse = BingoParser::parse(Rule::bingo, bingo_tables)
+        .map_err(|err| ParseError::from(err).within(input, bingo_tables))?
+        .next()
+        .unwrap();
+
+    let draws = extract_numbers(input, draws_parse.into_inner())?;
+
+    let tables = bingo_parse
+        .into_inner()
+        .filter(|x| x.as_rule() == Rule::bingo_table)
+        .map(|bt| {
+            let span = bt.as_str();
+            let lines = extract_lines(input, bt.into_inner())?;
+            if lines.iter().any(|line| line.len() != lines[0].len()) {
+                return Err(ParseError::at(input, span, "table rows differ in length"));
+            }
+            Ok(Table::from_lines(lines))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((draws, tables))
+}
+
+fn extract_lines<'r>(
+    input: &str,
+    rules: impl Iterator<Item = Pair<'r, Rule>>,
+) -> Result<Vec<Vec<usize>>, ParseError> {
+    rules
+        .filter(|x| x.as_rule() == Rule::bingo_line)
+        .map(|x| extract_numbers(input, x.into_inner()))
+        .collect()
+}
+
+fn extract_numbers<'r>(
+    input: &str,
+    rules: impl Iterator<Item = Pair<'r, Rule>>,
+) -> Result<Vec<usize>, ParseError> {
+    rules
+        .filter(|x| x.as_rule() == Rule::number)
+        .map(|x| parse_at(input, x.as_str()))
+        .collect()
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    lines: Vec<Vec<usize>>,
+    cols: Vec<Vec<usize>>,
+}
+
+impl Table {
+    fn from_lines(lines: Vec<Vec<usize>>) -> Self {
+        let height = lines.len();
+        assert_ne!(height, 0);
+        let width = lines[0].len();
+
+        let mut cols = vec![Vec::<usize>::with_capacity(height); width];
+        for line in lines.iter() {
+            for (col_no, col) in cols.iter_mut().enumerate() {
+                col.push(line[col_no]);
+            }
+        }
+        Self { lines, cols }
+    }
+
+    fn draw(&mut self, number: &usize) {
+        for line in &mut self.lines {
+            line.retain(|x| x != number);
+        }
+        for col in &mut self.cols {
+            col.retain(|x| x != number);
+        }
+    }
+
+    fn is_winner(&self) -> bool {
+        let winner_lines = self.lines.iter().find(|&x| x.is_empty());
+        let winner_cols = self.cols.iter().find(|&x| x.is_empty());
+        winner_lines.is_some() || winner_cols.is_some()
+    }
+
+    fn score(&self) -> usize {
+        self.lines.iter().flatten().sum::<usize>()
+    }
+}
+
+/// Scores of the winning tables, in the order they win.
+fn winning_scores(draws: &[usize], mut tables: Vec<Table>) -> Vec<usize> {
+    let mut scores = Vec::with_capacity(tables.len());
+
+    for draw in draws {
+        for table in &mut tables {
+            if !table.is_winner() {
+                table.draw(draw);
+                if table.is_winner() {
+                    scores.push(table.score() * draw);
+                }
+            }
+        }
+    }
+    scores
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input<'a> = (Vec<usize>, Vec<Table>);
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1((draws, tables): &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let scores = winning_scores(draws, tables.clone());
+        scores
+            .first()
+            .copied()
+            .ok_or(SolveError::new("no table wins"))
+    }
+
+    fn part2((draws, tables): &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let scores = winning_scores(draws, tables.clone());
+        scores
+            .last()
+            .copied()
+            .ok_or(SolveError::new("no table wins"))
+    }
+}
@@ -0,0 +1,66 @@
+use aoc_2dmap::prelude::*;
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day05-line.pest"]
+pub struct LineParser;
+
+/// How many lines cross each point.
+#[derive(Default)]
+struct Vents(SparseMap<usize>);
+
+impl Vents {
+    fn draw_line(&mut self, line: &Segment) {
+        self.0.draw(line, |count| *count += 1);
+    }
+
+    fn num_overlap(&self) -> usize {
+        self.0.tiles().filter(|&(_, &count)| count >= 2).count()
+    }
+}
+
+fn overlaps(lines: &[Segment], skip_diag: bool) -> usize {
+    let mut vents = Vents::default();
+    for line in lines.iter().filter(|l| !skip_diag || l.is_axis_aligned()) {
+        vents.draw_line(line);
+    }
+    vents.num_overlap()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input<'a> = Vec<Segment>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        LineParser::parse(Rule::lines, input)?
+            .next()
+            .unwrap()
+            .into_inner()
+            .filter(|outer| outer.as_rule() == Rule::line)
+            .map(|outer| {
+                let c = outer
+                    .into_inner()
+                    .filter(|inner| inner.as_rule() == Rule::number)
+                    .map(|inner| parse_at::<i32>(input, inner.as_str()))
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(Segment::new(Pos::new(c[0], c[1]), Pos::new(c[2], c[3])))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(overlaps(input, true))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(overlaps(input, false))
+    }
+}
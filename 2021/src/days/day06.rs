@@ -0,0 +1,80 @@
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+type FishState = u16;
+type FishCounts = HashMap<FishState, usize>;
+type Transitions = HashMap<FishState, FishState>;
+
+const FISH_SPAWN: FishState = 8;
+const FISH_RESET: FishState = 6;
+
+fn epoch(fish_counts: &FishCounts, transitions: &Transitions) -> FishCounts {
+    let mut next_fish_counts = FishCounts::new();
+    let spawning_fishes = fish_counts.get(&0).unwrap_or(&0);
+
+    for (cur_state, next_state) in transitions {
+        let next_state_fishes = fish_counts.get(cur_state).unwrap_or(&0);
+        *next_fish_counts.entry(*next_state).or_insert(0) += next_state_fishes;
+    }
+
+    *next_fish_counts.entry(FISH_SPAWN).or_insert(0) += spawning_fishes;
+
+    next_fish_counts
+}
+
+fn transitions() -> Transitions {
+    let mut transitions = Transitions::new();
+
+    // 'iterative' is boring, check below for 'poetic'
+    for i in 0..FISH_SPAWN {
+        transitions.insert(i + 1, i);
+    }
+    transitions.insert(0, FISH_RESET);
+    transitions
+}
+
+fn simulate(fish_counts: &FishCounts, epochs: usize) -> usize {
+    let transitions = transitions();
+    let mut fish_counts = fish_counts.clone();
+
+    for _ in 1..=epochs {
+        fish_counts = epoch(&fish_counts, &transitions);
+    }
+    fish_counts.values().sum()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input<'a> = FishCounts;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut fish_counts = FishCounts::new();
+        for token in input.trim().split(',') {
+            let fish_state = parse_at(input, token)?;
+            if fish_state > FISH_SPAWN {
+                return Err(ParseError::at(
+                    input,
+                    token,
+                    format!("fish timers go up to {}", FISH_SPAWN),
+                ));
+            }
+            *fish_counts.entry(fish_state).or_insert(0) += 1;
+        }
+        Ok(fish_counts)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(simulate(input, 80))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(simulate(input, 256))
+    }
+}
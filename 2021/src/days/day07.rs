@@ -0,0 +1,78 @@
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[allow(dead_code)]
+enum CrabEngine {
+    Linear,
+    Exponential,
+}
+
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq)]
+struct Crab(i32);
+
+impl Crab {
+    fn expenditure(&self, new_pos: &i32, engine_type: &CrabEngine) -> i32 {
+        let delta = _abs_diff(&self.0, new_pos);
+        match engine_type {
+            CrabEngine::Linear => delta,
+            CrabEngine::Exponential => (delta * (delta + 1)) >> 1,
+        }
+    }
+}
+
+fn min_expenditure(crabs: &[Crab], engine_type: CrabEngine) -> i32 {
+    let min_b = crabs.iter().min().unwrap().0;
+    let max_b = crabs.iter().max().unwrap().0;
+    let expenditures: Vec<i32> = (min_b..=max_b)
+        .map(|new_pos| {
+            crabs
+                .iter()
+                .map(|crab| crab.expenditure(&new_pos, &engine_type))
+                .sum()
+        })
+        .collect();
+    *expenditures.iter().min().unwrap()
+}
+
+fn median(numbers: &mut [i32]) -> i32 {
+    numbers.sort_unstable();
+    let mid = numbers.len() / 2;
+    numbers[mid]
+}
+
+fn expenditure(numbers: &[i32], median: &i32) -> i32 {
+    numbers.iter().map(|x| _abs_diff(x, median)).sum()
+}
+
+fn _abs_diff(l: &i32, r: &i32) -> i32 {
+    (*l - *r).abs()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input<'a> = Vec<i32>;
+    type P1 = i32;
+    type P2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|s| parse_at(input, s))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let mut numbers = input.clone();
+        let median = median(&mut numbers);
+        Ok(expenditure(&numbers, &median))
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let crabs: Vec<Crab> = input.iter().map(|x| Crab(*x)).collect();
+        Ok(min_expenditure(&crabs, CrabEngine::Exponential))
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use aoc_prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day08-digits.pest"]
+pub struct DigitsParser;
+
+type Segments = HashSet<char>;
+
+#[derive(Debug, Eq)]
+pub struct Digit {
+    segments: Segments,
+}
+
+impl Digit {
+    fn withdraw<F: Fn(&Segments) -> bool>(
+        predicate: F,
+        candidates: &mut Vec<Digit>,
+    ) -> Option<Self> {
+        let idx = candidates.iter().position(|y| predicate(&y.segments))?;
+        Some(candidates.swap_remove(idx))
+    }
+}
+
+impl Display for Digit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut chars: Vec<&char> = self.segments.iter().collect();
+        chars.sort();
+        write!(f, "{}", chars.into_iter().collect::<String>())
+    }
+}
+
+impl FromStr for Digit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            segments: HashSet::<char>::from_iter(s.chars()),
+        })
+    }
+}
+
+impl Hash for Digit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write(self.to_string().as_bytes())
+    }
+}
+
+impl PartialEq for Digit {
+    fn eq(&self, other: &Self) -> bool {
+        self.segments == other.segments
+    }
+}
+
+fn process_line(input: &str, line: Pair<'_, Rule>) -> Result<(usize, Option<i32>), ParseError> {
+    let span = line.as_str();
+    let patterns = extract_digits(line.clone().into_inner(), Rule::patterns);
+    let outputs = extract_digits(line.into_inner(), Rule::outputs);
+
+    let decode_map = get_decode_map(patterns)
+        .ok_or_else(|| ParseError::at(input, span, "patterns don't make up the ten digits"))?;
+
+    let easy = outputs
+        .iter()
+        .filter(|x| [2, 3, 4, 7].contains(&x.segments.len()))
+        .count();
+
+    let decoded: Vec<String> = outputs
+        .iter()
+        .map(|x| decode_map.get(x).cloned())
+        .collect::<Option<_>>()
+        .ok_or_else(|| ParseError::at(input, span, "output digit is not one of the patterns"))?;
+
+    Ok((easy, decoded.join("").parse::<i32>().ok()))
+}
+fn extract_digits<'r>(rules: impl Iterator<Item = Pair<'r, Rule>>, rule_type: Rule) -> Vec<Digit> {
+    rules
+        .filter(|x| x.as_rule() == rule_type)
+        .flat_map(|inner| inner.into_inner().flat_map(|x| x.as_str().parse()))
+        .collect()
+}
+
+/*
+1 <== 2 segments
+7 <== 3 segments
+4 <== 4 segments
+8 <== 7 segments
+3 <== 5 segments on including 1
+9 <== 6 segments on including 4
+0 <== 6 segments on including 1
+6 <== 6 segments on
+5 <== 5 segments on included in 6
+2 <== last one of the bunch
+*/
+fn get_decode_map(mut patterns: Vec<Digit>) -> Option<HashMap<Digit, String>> {
+    let p_ref = &mut patterns;
+    let one = Digit::withdraw(|x| x.len() == 2, p_ref)?;
+    let seven = Digit::withdraw(|x| x.len() == 3, p_ref)?;
+    let four = Digit::withdraw(|x| x.len() == 4, p_ref)?;
+    let eight = Digit::withdraw(|x| x.len() == 7, p_ref)?;
+    let three = Digit::withdraw(|x| x.len() == 5 && x.is_superset(&one.segments), p_ref)?;
+    let nine = Digit::withdraw(|x| x.len() == 6 && x.is_superset(&four.segments), p_ref)?;
+    let zero = Digit::withdraw(|x| x.len() == 6 && x.is_superset(&one.segments), p_ref)?;
+    let six = Digit::withdraw(|x| x.len() == 6, p_ref)?;
+    let five = Digit::withdraw(|x| x.len() == 5 && x.is_subset(&six.segments), p_ref)?;
+    let two = Digit::withdraw(|_| true, p_ref)?;
+
+    Some(
+        [zero, one, two, three, four, five, six, seven, eight, nine]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, d)| (d, format!("{}", idx)))
+            .collect(),
+    )
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    /// Per display: count of easy digits and the decoded output value.
+    type Input<'a> = Vec<(usize, Option<i32>)>;
+    type P1 = usize;
+    type P2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        DigitsParser::parse(Rule::lines, input)?
+            .next()
+            .unwrap()
+            .into_inner()
+            .filter(|x| x.as_rule() == Rule::line)
+            .map(|line| process_line(input, line))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(input.iter().map(|(easy, _)| easy).sum())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(input.iter().flat_map(|(_, opt)| opt).sum())
+    }
+}
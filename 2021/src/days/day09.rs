@@ -0,0 +1,50 @@
+use aoc_2dmap::prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+pub struct DepthMap(Map<u8>);
+
+const MAX_DEPTH: u8 = 9;
+
+impl DepthMap {
+    fn is_low_point(&self, pos: Pos) -> bool {
+        let depth = self.0[pos];
+        self.0.neighbors4(pos).all(|(_, &n_depth)| n_depth > depth)
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input<'a> = DepthMap;
+    type P1 = u64;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(DepthMap(Map::from_grid(input)?))
+    }
+
+    fn part1(depth_map: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(depth_map
+            .0
+            .iter()
+            .filter(|&pos| depth_map.is_low_point(pos))
+            .map(|pos| depth_map.0[pos] as u64 + 1)
+            .sum())
+    }
+
+    fn part2(depth_map: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        // basins are walled off by the highest points
+        let basins = depth_map
+            .0
+            .components(Connectivity::Four, |&depth| depth < MAX_DEPTH);
+        let mut sizes: Vec<_> = basins.components.iter().map(|basin| basin.size).collect();
+
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        Ok(sizes.iter().take(3).product())
+    }
+}
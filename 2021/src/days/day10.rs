@@ -0,0 +1,129 @@
+use aoc_prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+struct Pair(char, char, u32, u16);
+
+const PRS: [Pair; 4] = [
+    Pair('(', ')', 3, 1),
+    Pair('[', ']', 57, 2),
+    Pair('{', '}', 1197, 3),
+    Pair('<', '>', 25137, 4),
+];
+
+lazy_static! {
+    static ref OPENING: HashSet<char> = PRS.iter().map(|x| x.0).collect();
+    static ref CLOSING: HashSet<char> = PRS.iter().map(|x| x.1).collect();
+    static ref OPEN_TO_CLOSE: HashMap<char, char> = PRS.iter().map(|x| (x.0, x.1)).collect();
+    static ref ERR_SCORE: HashMap<char, u32> = PRS.iter().map(|x| (x.1, x.2)).collect();
+    static ref COMP_SCORE: HashMap<char, u16> = PRS.iter().map(|x| (x.1, x.3)).collect();
+}
+
+#[derive(Debug)]
+enum LineType {
+    Complete,
+    Incomplete(String), // completion sequence
+    Illegal(char),      // first illegal char
+}
+
+fn process_line<S: AsRef<str>>(line: S) -> LineType {
+    let mut stack = VecDeque::new();
+    for c in line.as_ref().chars() {
+        if is_opening(&c) {
+            stack.push_back(c);
+        }
+        if is_closing(&c) && !stack.is_empty() {
+            let last = stack.pop_back().unwrap();
+            assert!(is_opening(&last));
+            let expecting = open_to_close(&last);
+            if c != expecting {
+                return LineType::Illegal(c);
+            }
+        }
+    }
+    if stack.is_empty() {
+        LineType::Complete
+    } else {
+        LineType::Incomplete(stack.iter().rev().map(open_to_close).collect())
+    }
+}
+
+#[inline]
+fn is_opening(c: &char) -> bool {
+    OPENING.contains(c)
+}
+
+#[inline]
+fn is_closing(c: &char) -> bool {
+    CLOSING.contains(c)
+}
+
+#[inline]
+fn open_to_close(c: &char) -> char {
+    *OPEN_TO_CLOSE.get(c).unwrap()
+}
+
+fn comp_score<T: PrimInt>(completion: &dyn AsRef<str>) -> Option<T> {
+    let mut total: T = T::zero();
+    let base = T::from(5)?;
+    for c in completion.as_ref().chars() {
+        total = total.checked_mul(&base)?;
+        total = total.checked_add(&T::from(*COMP_SCORE.get(&c)?)?)?;
+    }
+    Some(total)
+}
+
+fn median<T: PrimInt>(numbers: &mut [T]) -> T {
+    numbers.sort_unstable();
+    numbers[numbers.len() / 2]
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input<'a> = Vec<&'a str>;
+    type P1 = u32;
+    type P2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let lines: Vec<_> = input.lines().collect();
+        for line in &lines {
+            if let Some(idx) = line.find(|c| !is_opening(&c) && !is_closing(&c)) {
+                return Err(ParseError::at(input, &line[idx..], "expected a bracket"));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(lines
+            .iter()
+            .map(process_line)
+            .filter_map(|x| match x {
+                LineType::Illegal(c) => ERR_SCORE.get(&c),
+                _ => None,
+            })
+            .sum())
+    }
+
+    fn part2(lines: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let p2_scores: Vec<_> = lines
+            .iter()
+            .map(process_line)
+            .filter_map(|x| match x {
+                LineType::Incomplete(s) => Some(s),
+                _ => None,
+            })
+            .map(|s| comp_score(&s))
+            .collect();
+
+        let mut scores: Vec<_> = p2_scores.into_iter().flatten().collect();
+        if scores.is_empty() {
+            return Err(SolveError::new("no incomplete lines"));
+        }
+        Ok(median(&mut scores))
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_2dmap::prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+const MAX_ENERGY: u16 = 9;
+/// Give up on octopuses that never synchronise, since they may cycle forever.
+const MAX_STEPS: usize = 100_000;
+
+#[derive(Default, Copy, Clone, PartialEq, Eq)]
+struct Octo {
+    energy: u16,
+    flashed: bool,
+    flashing: bool,
+}
+
+impl FromChar for Octo {
+    type Err = &'static str;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        c.to_digit(10)
+            .map(|energy| Self {
+                energy: energy as u16,
+                ..Self::default()
+            })
+            .ok_or("expected an energy level")
+    }
+}
+
+#[derive(Clone)]
+pub struct OctoMap(Automaton<Octo>);
+
+impl OctoMap {
+    // return number of flashes
+    fn step(&mut self) -> usize {
+        for octo in self.0.map_mut().get_tiles_mut() {
+            octo.energy += 1;
+        }
+
+        // flashes cascade one generation at a time: octopuses flash once
+        // when over MAX_ENERGY, each flash feeding their neighbors in the next
+        self.0.run_until_stable(|pos, octo, map| {
+            let fed = map.neighbors8(pos).filter(|(_, n)| n.flashing).count();
+            let energy = octo.energy + fed as u16;
+            let flashing = !octo.flashed && energy > MAX_ENERGY;
+            Octo {
+                energy,
+                flashed: octo.flashed || flashing,
+                flashing,
+            }
+        });
+
+        let mut flashes = 0;
+        for octo in self.0.map_mut().get_tiles_mut() {
+            if octo.flashed {
+                *octo = Octo::default();
+                flashes += 1;
+            }
+        }
+        flashes
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input<'a> = OctoMap;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(OctoMap(Automaton::new(Map::from_grid(input)?)))
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let mut map = map.clone();
+        Ok((0..100).map(|_| map.step()).sum())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let mut map = map.clone();
+        let octo_count = map.0.map().get_tiles().len();
+        (1..=MAX_STEPS)
+            .find(|_| map.step() == octo_count)
+            .ok_or(SolveError::new(format!(
+                "octopuses don't flash together within {} steps",
+                MAX_STEPS
+            )))
+    }
+}
@@ -0,0 +1,111 @@
+use aoc_prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day12-graph.pest"]
+pub struct GraphParser;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Cave<'a> {
+    Start,
+    End,
+    Node { name: &'a str, small: bool },
+}
+
+impl<'a> From<&'a str> for Cave<'a> {
+    fn from(s: &'a str) -> Cave<'a> {
+        match s {
+            "start" => Self::Start,
+            "end" => Self::End,
+            name => Cave::Node {
+                name,
+                small: is_lower(name),
+            },
+        }
+    }
+}
+
+pub type Graph<'g> = MultiMap<Cave<'g>, Cave<'g>>;
+
+fn read_input(input: &str) -> Result<Graph<'_>, ParseError> {
+    let mut inner = Vec::with_capacity(2);
+
+    let graph = GraphParser::parse(Rule::lines, input)?
+        .next()
+        .unwrap()
+        .into_inner()
+        .filter(|pair| pair.as_rule() == Rule::line)
+        .flat_map(|pair| {
+            inner.clear();
+            inner.extend(pair.into_inner());
+            let (from, to) = (inner[0].as_str().into(), inner[1].as_str().into());
+            [(from, to), (to, from)]
+        })
+        .fold(Graph::with_capacity(256), |mut graph, (from, to)| {
+            graph.insert(from, to);
+            graph
+        });
+
+    for (cave, name) in [(Cave::Start, "start"), (Cave::End, "end")] {
+        if !graph.contains_key(&cave) {
+            return Err(ParseError::new(1, 1, format!("no '{}' cave", name)));
+        }
+    }
+    Ok(graph)
+}
+
+fn is_lower(s: &str) -> bool {
+    s.chars().all(char::is_lowercase)
+}
+
+fn solve(graph: &Graph<'_>, allow_small_revisit: bool) -> Option<usize> {
+    let mut paths = 0;
+
+    let mut deck = VecDeque::with_capacity(2048);
+    deck.push_front((vec![Cave::Start], false));
+
+    while let Some((path, small_revisited)) = deck.pop_front() {
+        for cave in graph.get_vec(path.last()?)? {
+            match *cave {
+                Cave::Start => continue,
+                Cave::End => paths += 1,
+                Cave::Node { name: _, small } => {
+                    let mut small_revisited = small_revisited;
+                    if small && path.contains(cave) {
+                        if !allow_small_revisit || small_revisited {
+                            continue;
+                        }
+                        small_revisited = true;
+                    };
+                    let new_path = path.iter().cloned().chain([*cave]).collect();
+                    deck.push_front((new_path, small_revisited));
+                }
+            }
+        }
+    }
+    Some(paths)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input<'a> = Graph<'a>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        read_input(input)
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        solve(graph, false).ok_or(SolveError::new("dead end in the cave graph"))
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        solve(graph, true).ok_or(SolveError::new("dead end in the cave graph"))
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_2dmap::prelude::*;
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day13-folds.pest"]
+pub struct FoldsParser;
+
+pub struct Fold {
+    axis: Axis,
+    at: i32,
+}
+
+const DOT: char = '#';
+const BLANK: char = ' ';
+
+/// Fold the far half of the paper over the near one, as long as the fold
+/// line is on the paper.
+fn fold(map: &Map<char>, fold: &Fold) -> Result<Map<char>, SolveError> {
+    let Fold { axis, at } = *fold;
+    let size = axis.map(map.size.x, map.size.y);
+    if at >= size.x {
+        return Err(SolveError::new(format!(
+            "fold line {} is outside the paper",
+            at
+        )));
+    }
+
+    let near = map.crop((0, 0), axis.map(at, size.y));
+    // +1 because we eat the fold line
+    let far = map
+        .crop(axis.map(at + 1, 0), axis.map(size.x - at - 1, size.y))
+        .flip(axis);
+
+    // the halves line up at the fold, so pad the shorter one at the front
+    let pad = |half: Map<char>, by: i32| Map::fill(axis.map(by, size.y), BLANK).concat(&half, axis);
+    let (near, far) = match at - (size.x - at - 1) {
+        d if d > 0 => (near, pad(far, d)),
+        d => (pad(near, -d), far),
+    };
+
+    Ok(Map::new(
+        near.size,
+        near.get_tiles()
+            .iter()
+            .zip(far.get_tiles())
+            .map(|(&a, &b)| if a == DOT || b == DOT { DOT } else { BLANK }),
+    ))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input<'a> = (Map<char>, Vec<Fold>);
+    type P1 = usize;
+    type P2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let parsed = FoldsParser::parse(Rule::lines, input)?.next().unwrap();
+
+        let mut points = Vec::<Pos>::new();
+        let mut folds = Vec::<Fold>::new();
+        for rule in parsed.into_inner() {
+            match rule.as_rule() {
+                Rule::point => {
+                    let coords: Vec<_> = rule.as_str().split(',').collect();
+                    points.push(Pos {
+                        x: parse_at(input, coords[0])?,
+                        y: parse_at(input, coords[1])?,
+                    });
+                }
+                Rule::fold => {
+                    let fold: Vec<_> = rule.as_str().split('=').collect();
+                    folds.push(Fold {
+                        axis: {
+                            match fold[0] {
+                                "x" => Axis::X,
+                                "y" => Axis::Y,
+                                _ => unreachable!(),
+                            }
+                        },
+                        at: parse_at(input, fold[1])?,
+                    })
+                }
+                _ => (),
+            }
+        }
+        let max_x = points.iter().map(|p| p.x).max().unwrap_or_default() + 1;
+        let max_y = points.iter().map(|p| p.y).max().unwrap_or_default() + 1;
+
+        let mut map = Map::fill((max_x, max_y), BLANK);
+        for point in points {
+            map.set(point, DOT);
+        }
+
+        Ok((map, folds))
+    }
+
+    fn part1((map, folds): &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let first = folds
+            .first()
+            .ok_or(SolveError::new("no fold instructions"))?;
+        let map = fold(map, first)?;
+        Ok(map.get_tiles().iter().filter(|&&x| x == DOT).count())
+    }
+
+    fn part2((map, folds): &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let mut map = map.clone();
+        for f in folds {
+            map = fold(&map, f)?;
+        }
+        Ok(map.to_string())
+    }
+}
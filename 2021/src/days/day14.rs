@@ -0,0 +1,123 @@
+use aoc_prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day14-polymer.pest"]
+pub struct FoldsParser;
+
+fn extract_freqs(
+    pairs: &HashMap<[char; 2], usize>,
+    first: char,
+    last: char,
+) -> BTreeMap<char, usize> {
+    let mut freqs: HashMap<char, usize> = HashMap::new();
+    for (pair, count) in pairs {
+        *freqs.entry(pair[0]).or_insert(0) += count;
+        *freqs.entry(pair[1]).or_insert(0) += count;
+    }
+
+    freqs
+        .into_iter()
+        .map(|(c, s)| {
+            if c == first || c == last {
+                (c, s.div_ceil(2))
+            } else {
+                (c, s / 2)
+            }
+        })
+        .collect()
+}
+
+fn simulate_pairs(
+    pairs: &HashMap<[char; 2], usize>,
+    insertions: &HashMap<[char; 2], char>,
+    steps: usize,
+) -> HashMap<[char; 2], usize> {
+    let mut pairs = pairs.clone();
+    for _ in 1..=steps {
+        let mut new_pairs = HashMap::<[char; 2], usize>::new();
+        for (pair, count) in pairs {
+            if let Some(mid) = insertions.get(&pair) {
+                *new_pairs.entry([pair[0], *mid]).or_insert(0) += count;
+                *new_pairs.entry([*mid, pair[1]]).or_insert(0) += count;
+            }
+        }
+        pairs = new_pairs;
+    }
+    pairs
+}
+
+pub struct Polymer {
+    pairs: HashMap<[char; 2], usize>,
+    insertions: HashMap<[char; 2], char>,
+    first: char,
+    last: char,
+}
+
+impl Polymer {
+    fn spread(&self, steps: usize) -> usize {
+        let pairs = simulate_pairs(&self.pairs, &self.insertions, steps);
+        let freqs = extract_freqs(&pairs, self.first, self.last);
+        let (max, min) = (freqs.values().max(), freqs.values().min());
+        max.zip(min).map_or(0, |(max, min)| max - min)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input<'a> = Polymer;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let parsed = FoldsParser::parse(Rule::lines, input)?.next().unwrap();
+
+        let mut pairs = HashMap::<[char; 2], usize>::new();
+        let mut insertions = HashMap::<[char; 2], char>::new();
+        let mut first = char::default();
+        let mut last = char::default();
+
+        for rule in parsed.into_inner() {
+            match rule.as_rule() {
+                Rule::template => {
+                    let tpl: Vec<_> = rule.as_str().chars().collect();
+                    first = tpl[0];
+                    last = tpl[tpl.len() - 1];
+                    tpl.windows(2).for_each(|x| {
+                        *pairs.entry([x[0], x[1]]).or_insert(0) += 1;
+                    });
+                }
+                Rule::insertion => {
+                    // the grammar guarantees exactly three letters
+                    let letters: Vec<_> = rule
+                        .as_str()
+                        .chars()
+                        .filter(char::is_ascii_uppercase)
+                        .collect();
+                    insertions.insert([letters[0], letters[1]], letters[2]);
+                }
+                _ => (),
+            }
+        }
+
+        Ok(Polymer {
+            pairs,
+            insertions,
+            first,
+            last,
+        })
+    }
+
+    fn part1(polymer: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(polymer.spread(10))
+    }
+
+    fn part2(polymer: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(polymer.spread(40))
+    }
+}
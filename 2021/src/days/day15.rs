@@ -0,0 +1,107 @@
+use std::hash::Hash;
+
+use aoc_2dmap::prelude::*;
+use aoc_dijsktra::{Dijsktra, GameState, Transform};
+use aoc_prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash, Clone)]
+struct State {
+    pos: Pos,
+    goal: Pos,
+}
+
+struct Move {
+    to: Pos,
+    cost: usize,
+}
+
+impl GameState<Map<usize>> for State {
+    type Steps = ArrayVec<Move, 4>;
+
+    fn accept(&self, _cost: usize, _ctx: &mut Map<usize>) -> bool {
+        self.pos == self.goal
+    }
+
+    fn steps(&self, context: &mut Map<usize>) -> Self::Steps {
+        context
+            .neighbors4(self.pos)
+            .map(|(to, &cost)| Move { to, cost })
+            .collect()
+    }
+}
+
+impl Transform<State> for Move {
+    fn cost(&self) -> usize {
+        self.cost
+    }
+
+    fn transform(&self, state: &State) -> State {
+        State {
+            pos: self.to,
+            goal: state.goal,
+        }
+    }
+}
+
+/// Risk levels wrap around from 9 back to 1.
+fn inc_risk(risk: usize, by: i32) -> usize {
+    (risk - 1 + by as usize) % 9 + 1
+}
+
+/// Start at the top left corner, heading for the bottom right one.
+fn start(map: &Map<usize>) -> State {
+    State {
+        pos: Pos::default(),
+        goal: (map.size + (-1, -1).into()),
+    }
+}
+
+fn solve(map: &mut Map<usize>) -> Result<usize, SolveError> {
+    start(map)
+        .dijsktra(map)
+        .ok_or(SolveError::new("no path to the bottom right corner"))
+}
+
+/// Total risk and positions of a least risky route, from the top left corner
+/// to the bottom right one.
+pub fn route(map: &Map<usize>) -> Option<(usize, Vec<Pos>)> {
+    let path = start(map).dijsktra_path(&mut map.clone())?;
+    Some((path.cost, path.states.into_iter().map(|s| s.pos).collect()))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input<'a> = Map<usize>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let map = Map::from_grid(input)?;
+        if map.size.x == 0 {
+            return Err(ParseError::new(1, 1, "empty input"));
+        }
+        if let Some(pos) = map.iter().find(|&pos| map[pos] == 0) {
+            return Err(ParseError::new(
+                pos.y as usize + 1,
+                pos.x as usize + 1,
+                "risk levels go from 1 to 9",
+            ));
+        }
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        solve(&mut map.clone())
+    }
+
+    fn part2(map: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let mut large_map = map.tile(5, 5, |&risk, tile| inc_risk(risk, tile.x + tile.y));
+        solve(&mut large_map)
+    }
+}
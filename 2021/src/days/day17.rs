@@ -0,0 +1,120 @@
+use std::ops::RangeInclusive;
+
+use aoc_prelude::*;
+use regex::Regex;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+lazy_static! {
+    static ref TARGET_REGEX: Regex =
+        Regex::new(r"target area: x=(-?\d+)\.\.(-?\d+), y=(-?\d+)\.\.(-?\d+)").unwrap();
+}
+
+pub struct Target {
+    x: RangeInclusive<isize>,
+    y: RangeInclusive<isize>,
+}
+
+impl Target {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let captures = TARGET_REGEX.captures(input).ok_or(ParseError::new(
+            1,
+            1,
+            "expected 'target area: x=A..B, y=C..D'",
+        ))?;
+        let bound = |idx: usize| parse_at::<isize>(input, captures.get(idx).unwrap().as_str());
+        Ok(Self {
+            x: bound(1)?..=bound(2)?,
+            y: bound(3)?..=bound(4)?,
+        })
+    }
+}
+
+#[derive(Default, Debug, Copy, Clone)]
+struct State {
+    x: isize,
+    y: isize,
+    vx: isize,
+    vy: isize,
+}
+
+impl State {
+    fn new(vx: isize, vy: isize) -> Self {
+        Self {
+            vx,
+            vy,
+            ..State::default()
+        }
+    }
+
+    fn step(self) -> Self {
+        let x = self.x + self.vx;
+        let y = self.y + self.vy;
+
+        let vx = match self.vx {
+            v if v < 0 => v + 1,
+            v if v > 0 => v - 1,
+            v => v,
+        };
+        let vy = self.vy - 1;
+        Self { x, y, vx, vy }
+    }
+
+    fn overshot(&self, target: &Target) -> bool {
+        self.x > *target.x.end() || self.y < *target.y.start()
+    }
+
+    fn hit(&self, target: &Target) -> bool {
+        target.x.contains(&self.x) && target.y.contains(&self.y)
+    }
+}
+
+fn solve_triangle(sum: isize) -> isize {
+    ((sum * 2 + 1) as f32).sqrt().floor() as isize
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input<'a> = Target;
+    type P1 = isize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Target::parse(input)
+    }
+
+    fn part1(target: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let y_min = *target.y.start();
+        Ok(y_min * (y_min + 1) / 2)
+    }
+
+    fn part2(target: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let (x_min, x_max) = (*target.x.start(), *target.x.end());
+        let y_min = *target.y.start();
+
+        let vx_min = solve_triangle(x_min);
+        let vx_max = x_max;
+
+        let vy_min = y_min;
+        let vy_max = y_min.abs();
+
+        let mut hits = 0;
+        for vx in vx_min..=vx_max {
+            for vy in vy_min..=vy_max {
+                let mut s = State::new(vx, vy);
+                while !s.overshot(target) {
+                    s = s.step();
+                    if s.hit(target) {
+                        hits += 1;
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(hits)
+    }
+}
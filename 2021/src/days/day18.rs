@@ -0,0 +1,289 @@
+#![allow(clippy::suspicious_arithmetic_impl)]
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, BitAnd};
+use std::str::FromStr;
+
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day18-snails.pest"]
+pub struct SnailParser;
+
+#[derive(Debug, Clone)]
+pub enum BTree<T> {
+    Leaf(T),
+    Branch {
+        left: Box<BTree<T>>,
+        right: Box<BTree<T>>,
+    },
+}
+
+impl<T> BitAnd for BTree<T> {
+    type Output = BTree<T>;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        BTree::Branch {
+            left: Box::new(self),
+            right: Box::new(rhs),
+        }
+    }
+}
+
+impl<T> BTree<T> {
+    fn fold<F: Fn(T, T) -> T + Copy>(self, op: F) -> T {
+        match self {
+            Self::Leaf(t) => t,
+            Self::Branch { left, right } => op(left.fold(op), right.fold(op)),
+        }
+    }
+
+    fn visit<F: Fn(T) -> T + Copy>(self, f: F) -> Self {
+        match self {
+            Self::Leaf(t) => Self::Leaf(f(t)),
+            Self::Branch { left, right } => left.visit(f) & right.visit(f),
+        }
+    }
+
+    fn topo(&mut self) -> ArrayVec<&mut T, 64> {
+        let mut nodes = ArrayVec::new();
+        self._topo_rec(&mut nodes);
+        nodes
+    }
+
+    fn _topo_rec<'a>(&'a mut self, recv: &mut ArrayVec<&'a mut T, 64>) {
+        match self {
+            Self::Leaf(t) => recv.push(t),
+            Self::Branch { left, right } => {
+                left._topo_rec(recv);
+                right._topo_rec(recv);
+            }
+        }
+    }
+
+    fn get_leaf(&self) -> Option<&T> {
+        if let Self::Leaf(n) = self {
+            Some(n)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct Node<T> {
+    val: T,
+    depth: usize,
+}
+
+impl<T> Node<T> {
+    fn new(val: T, depth: usize) -> Self {
+        Self { val, depth }
+    }
+
+    fn deepen(self) -> Node<T> {
+        Node {
+            depth: self.depth + 1,
+            ..self
+        }
+    }
+}
+
+const TOMBSTONE: isize = -1;
+impl Node<isize> {
+    fn is_tombstone(&self) -> bool {
+        self.val == TOMBSTONE
+    }
+
+    fn is_splittable(&self) -> bool {
+        self.val < 0
+    }
+}
+
+const EXPLODE_DEPTH: usize = 4;
+impl BTree<Node<isize>> {
+    fn explode(&mut self) -> bool {
+        let mut nodes = self.topo();
+
+        for i in 0..(nodes.len() - 1) {
+            let p = nodes[i].val;
+            let n = nodes[i + 1].val;
+            if nodes[i + 1].depth > EXPLODE_DEPTH && nodes[i].depth == nodes[i + 1].depth {
+                if i > 0 {
+                    nodes[i - 1].val += p;
+                }
+                if i + 2 < nodes.len() {
+                    nodes[i + 2].val += n;
+                }
+                nodes[i].val = TOMBSTONE;
+                nodes[i + 1].val = TOMBSTONE;
+
+                drop(nodes);
+                self.compact();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn split(&mut self) -> bool {
+        let mut nodes = self.topo();
+        for node in &mut nodes {
+            let val = node.val;
+            if val >= 10 {
+                node.val = -val;
+
+                // ArrayVec implements Drop so we manually drop here instead
+                // of the end of the loop, so we can move `self` into `compact`
+                drop(nodes);
+
+                self.compact();
+                return true;
+            }
+        }
+        false
+    }
+
+    fn compact(&mut self) {
+        match self {
+            Self::Branch { left, right } => {
+                if let (Some(ln), Some(rn)) = (left.get_leaf(), right.get_leaf()) {
+                    if ln.is_tombstone() && rn.is_tombstone() {
+                        *self = Self::Leaf(Node::new(0, ln.depth - 1));
+                        return;
+                    }
+                }
+                left.compact();
+                right.compact();
+            }
+            Self::Leaf(node) if node.is_splittable() => {
+                let (val, depth) = (-node.val, node.depth);
+                let rem = val % 2;
+                *self = Self::Leaf(Node::new(val / 2, depth + 1))
+                    & Self::Leaf(Node::new(val / 2 + rem, depth + 1));
+            }
+            _ => (),
+        }
+    }
+
+    fn magnitude(self) -> isize {
+        self.fold(|x, y| Node::new(3 * x.val + 2 * y.val, x.depth))
+            .val
+    }
+}
+
+pub type SnailNum = BTree<Node<isize>>;
+
+fn add_snails(t1: &SnailNum, t2: &SnailNum) -> SnailNum {
+    let mut t_sum = t1.clone() + t2.clone();
+
+    let mut more = true;
+
+    while more {
+        let res = t_sum.explode();
+        if !res {
+            more = t_sum.split();
+        }
+    }
+    t_sum
+}
+
+impl Display for SnailNum {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Leaf(x) => write!(f, "{}", x.val),
+            Self::Branch { left, right } => write!(f, "[{}, {}]", *left, *right),
+        }
+    }
+}
+
+impl<T> Add for BTree<Node<T>> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.visit(Node::deepen) & rhs.visit(Node::deepen)
+    }
+}
+
+impl<T> BTree<Node<T>>
+where
+    T: FromStr + Debug,
+    <T as FromStr>::Err: Display,
+{
+    /// Parse a tree from `line`, a line of `input`.
+    fn from_line(input: &str, line: &str) -> Result<Self, ParseError> {
+        let parsed = SnailParser::parse(Rule::expr, line)
+            .map_err(|err| ParseError::from(err).within(input, line))?
+            .next()
+            .unwrap();
+
+        process_pair(input, parsed, 0)
+    }
+}
+
+fn process_pair<T>(
+    input: &str,
+    pair: Pair<Rule>,
+    depth: usize,
+) -> Result<BTree<Node<T>>, ParseError>
+where
+    T: FromStr + Debug,
+    <T as FromStr>::Err: Display,
+{
+    Ok(match pair.as_rule() {
+        Rule::number => {
+            let node = Node::new(parse_at::<T>(input, pair.as_str())?, depth);
+            BTree::Leaf(node)
+        }
+        Rule::expr => {
+            let nodes: ArrayVec<_, 2> = pair.into_inner().collect();
+            process_pair(input, nodes[0].to_owned(), depth + 1)?
+                & process_pair(input, nodes[1].to_owned(), depth + 1)?
+        }
+        _ => unreachable!(),
+    })
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input<'a> = Vec<SnailNum>;
+    type P1 = isize;
+    type P2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| SnailNum::from_line(input, line))
+            .collect()
+    }
+
+    fn part1(trees: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let mut trees = trees.iter();
+        let mut t = trees
+            .next()
+            .ok_or(SolveError::new("no snailfish numbers to add"))?
+            .clone();
+        for tree in trees {
+            t = add_snails(&t, tree);
+        }
+        Ok(t.magnitude())
+    }
+
+    fn part2(trees: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let mut max_sum = 0;
+        trees.iter().tuple_combinations().for_each(|(x, y)| {
+            max_sum = itermax([
+                max_sum,
+                add_snails(x, y).magnitude(),
+                add_snails(y, x).magnitude(),
+            ])
+            .unwrap();
+        });
+        Ok(max_sum)
+    }
+}
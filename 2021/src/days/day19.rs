@@ -0,0 +1,230 @@
+use aoc_2dmap::prelude::*;
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Parser)]
+#[grammar = "parsers/day19-scanners.pest"]
+pub struct ScannerParser;
+
+type Point = Pos3<i16>;
+
+#[derive(Clone, PartialEq, Eq)]
+struct Scanner {
+    points: Vec<Point>,
+    /// Positions of the scanners folded into this one, itself included.
+    origins: Vec<Point>,
+}
+
+type DiffFreq = HashMap<Point, usize>;
+
+impl Scanner {
+    fn permute(&self, permute: &Permute) -> Scanner {
+        let points = self.points.iter().map(|&p| permute.apply(p)).collect();
+        let origins = self.origins.iter().map(|&p| permute.apply(p)).collect();
+        Scanner { points, origins }
+    }
+
+    fn find_permute(
+        &self,
+        other: &Scanner,
+        permutes: Vec<Permute>,
+        diff_freq: &mut DiffFreq,
+    ) -> Option<(Permute, Point)> {
+        for (idx, permute) in permutes.iter().enumerate() {
+            diff_freq.clear();
+            if let Some(offset) = self.find_offset(other.permute(permute), diff_freq) {
+                return Some((permutes[idx], offset));
+            }
+        }
+        None
+    }
+
+    fn find_offset(&self, other: Scanner, diff_freq: &mut DiffFreq) -> Option<Point> {
+        for x in iproduct!(&self.points, other.points).map(|(x, y)| *x - y) {
+            let new_freq = diff_freq.entry(x).or_insert(0);
+            *new_freq += 1;
+            if *new_freq == 12 {
+                return Some(x);
+            }
+        }
+        None
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Permute {
+    first_rota: [i16; 3],
+    second_rota: [i16; 3],
+}
+
+impl Permute {
+    fn apply(&self, p: Point) -> Point {
+        rotate(rotate(p, self.first_rota), self.second_rota)
+    }
+}
+
+fn rotate(p: Point, rota: [i16; 3]) -> Point {
+    let mut new_pos = [0; 3];
+    for (old_idx, r) in rota.iter().enumerate() {
+        let idx = (r.abs() - 1) as usize;
+        new_pos[old_idx] = p[idx] * r.signum();
+    }
+    new_pos.into()
+}
+
+/// Fold overlapping scanners into each other, returning whether any were.
+fn collapse(
+    scanners: &mut BTreeMap<usize, Scanner>,
+    permutes: &[Permute],
+    diff_freq: &mut DiffFreq,
+) -> bool {
+    let mut tree: Vec<_> = Vec::with_capacity(32);
+
+    for (k0, k1) in scanners.keys().rev().tuple_combinations() {
+        if let Some((permute, offset)) =
+            scanners[k0].find_permute(&scanners[k1], permutes.into(), diff_freq)
+        {
+            tree.push((*k0, *k1, permute, offset));
+        }
+    }
+
+    let from: BTreeSet<_> = tree.iter().map(|x| x.0).collect();
+    let to: BTreeSet<_> = tree.iter().map(|x| x.1).collect();
+    let leaves: Vec<_> = to.difference(&from).collect();
+    for leaf_idx in &leaves {
+        let tpl = tree.iter().find(|x| x.1 == **leaf_idx).unwrap();
+        let folded = fold(&scanners[&tpl.0], &scanners[&tpl.1], &tpl.2, tpl.3);
+        scanners.remove(&tpl.1);
+        scanners.entry(tpl.0).and_modify(|s| *s = folded);
+    }
+    !leaves.is_empty()
+}
+
+fn fold(s0: &Scanner, s1: &Scanner, p: &Permute, o: Point) -> Scanner {
+    let moved = s1.permute(p);
+    let mut points: BTreeSet<_> = moved.points.iter().map(|&x| x + o).collect();
+    points.extend(&s0.points);
+    let mut origins: Vec<_> = moved.origins.iter().map(|&x| x + o).collect();
+    origins.extend(&s0.origins);
+    Scanner {
+        points: points.into_iter().collect(),
+        origins,
+    }
+}
+
+/// The 24 rotations: face each of the six ways, then spin around z.
+fn permutes() -> Vec<Permute> {
+    let x_y_rot = vec![
+        [1, 2, 3],
+        [-3, 2, 1],
+        [-1, 2, -3],
+        [3, 2, -1],
+        [1, -3, 2],
+        [1, 3, -2],
+    ];
+    let z_rot = vec![[1, 2, 3], [-2, 1, 3], [-1, -2, 3], [2, -1, 3]];
+
+    iproduct!(x_y_rot, z_rot)
+        .map(|(first_rota, second_rota)| Permute {
+            first_rota,
+            second_rota,
+        })
+        .collect()
+}
+
+fn scanners(input: &str) -> Result<BTreeMap<usize, Scanner>, ParseError> {
+    let lines = input.lines().filter(|line| !line.is_empty());
+    let mut i: usize = 0;
+
+    let mut scan_points = Vec::<(usize, Point)>::new();
+
+    for line in lines {
+        let parse_result = ScannerParser::parse(Rule::line, line)
+            .map_err(|err| ParseError::from(err).within(input, line))?
+            .next()
+            .unwrap();
+        match parse_result.as_rule() {
+            Rule::scanner => {
+                i += 1;
+            }
+            Rule::point => {
+                let coords: ArrayVec<i16, 3> = parse_result
+                    .into_inner()
+                    .map(|x| parse_at::<i16>(input, x.as_str()))
+                    .collect::<Result<_, _>>()?;
+                // the grammar makes sure there are three of them
+                scan_points.push((i, Point::new(coords.into_inner().unwrap())));
+            }
+            _ => (),
+        }
+    }
+
+    let mut scanners = BTreeMap::<usize, Scanner>::new();
+    for (idx, pgroup) in &scan_points.into_iter().chunk_by(|(x, _)| *x) {
+        let points = pgroup.map(|(_, p)| p).collect();
+        // every scanner sits at its own origin, track it through the folds
+        let origins = vec![Point::zero()];
+        scanners.insert(idx, Scanner { points, origins });
+    }
+    Ok(scanners)
+}
+
+/// Beacon count and scanner positions, once all scanners are aligned.
+pub struct Aligned {
+    beacons: usize,
+    origins: Vec<Point>,
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u8 = 19;
+
+    /// Aligning is shared by both parts, so it's done while parsing, but
+    /// failing to is up to the parts to report.
+    type Input<'a> = Result<Aligned, SolveError>;
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let permutes = permutes();
+        let mut scanners = scanners(input)?;
+        let mut diff_freq = DiffFreq::with_capacity(2 ^ 12);
+
+        // this is brutal, we totally don't need to do multiple collapses
+        // since we'd have found all possible offset points from the
+        // first pass
+        while scanners.len() > 1 {
+            if !collapse(&mut scanners, &permutes, &mut diff_freq) {
+                return Ok(Err(SolveError::new(
+                    "scanners don't overlap enough to be aligned",
+                )));
+            }
+        }
+
+        let last = scanners
+            .into_values()
+            .last()
+            .ok_or(ParseError::new(1, 1, "no scanners"))?;
+
+        Ok(Ok(Aligned {
+            beacons: last.points.len(),
+            origins: last.origins,
+        }))
+    }
+
+    fn part1(aligned: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(aligned.as_ref().map_err(Clone::clone)?.beacons)
+    }
+
+    fn part2(aligned: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let aligned = aligned.as_ref().map_err(Clone::clone)?;
+        let mut m_d = 0;
+        for (p, q) in aligned.origins.iter().tuple_combinations() {
+            m_d = max(m_d, p.manhattan(*q) as usize);
+        }
+        Ok(m_d)
+    }
+}
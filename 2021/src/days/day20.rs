@@ -0,0 +1,84 @@
+use aoc_2dmap::prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+const LIGHT: char = '#';
+const DARK: char = '.';
+
+/// Enhance the image once. The background, which stands for the rest of the
+/// infinite image, is enhanced along with it and may flip between steps.
+fn enhance(image: &SparseMap<char>, algo: &[char]) -> SparseMap<char> {
+    let background = algo[if *image.background() == LIGHT { 511 } else { 0 }];
+    let mut enhanced = SparseMap::new(background);
+
+    if let Some((min, max)) = image.bounds() {
+        for y in min.y - 1..=max.y + 1 {
+            for x in min.x - 1..=max.x + 1 {
+                let pos = Pos { x, y };
+                let algo_idx = image
+                    .window(pos, 1)
+                    .fold(0, |idx, &pixel| idx << 1 | (pixel == LIGHT) as usize);
+                enhanced.set(pos, algo[algo_idx]);
+            }
+        }
+    }
+    enhanced
+}
+
+fn enhanced_pixels(algo: &str, image: &SparseMap<char>, steps: usize) -> usize {
+    let algo: Vec<char> = algo.chars().collect();
+    let mut image = image.clone();
+    for _ in 0..steps {
+        image = enhance(&image, &algo);
+    }
+    image.tiles().filter(|&(_, &pixel)| pixel == LIGHT).count()
+}
+
+fn check_pixels(input: &str, pixels: &str) -> Result<(), ParseError> {
+    match pixels.find(|c| c != LIGHT && c != DARK && c != '\n') {
+        Some(idx) => Err(ParseError::at(input, &pixels[idx..], "expected '#' or '.'")),
+        None => Ok(()),
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input<'a> = (&'a str, SparseMap<char>);
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut sections = input.splitn(3, '\n');
+
+        let algo = sections.next().unwrap_or_default();
+        check_pixels(input, algo)?;
+        if algo.len() != 512 {
+            return Err(ParseError::at(
+                input,
+                algo,
+                format!("algorithm has {} pixels, expected 512", algo.len()),
+            ));
+        }
+        if let Some(line) = sections.next().filter(|line| !line.is_empty()) {
+            return Err(ParseError::at(input, line, "expected an empty line"));
+        }
+
+        let image = sections.next().unwrap_or_default();
+        check_pixels(input, image)?;
+        let map =
+            Map::from_grid(image).map_err(|err| ParseError::from(err).within(input, image))?;
+        Ok((algo, SparseMap::from_map(map, DARK)))
+    }
+
+    fn part1((algo, map): &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(enhanced_pixels(algo, map, 2))
+    }
+
+    fn part2((algo, map): &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(enhanced_pixels(algo, map, 50))
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_prelude::*;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+lazy_static! {
+    static ref DIRAC: HashMap<u64, u64> = {
+        [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)]
+            .iter()
+            .cloned()
+            .collect()
+    };
+}
+
+const DIRAC_SCORE: u64 = 21;
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Player {
+    pos: u64,
+    score: u64,
+}
+
+impl Player {
+    fn roll(&self, roll: u64) -> Player {
+        let mut new_pos = (self.pos + roll) % 10;
+        if new_pos == 0 {
+            new_pos = 10;
+        }
+        Player {
+            pos: new_pos,
+            score: self.score + new_pos,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct State {
+    p1: Player,
+    p2: Player,
+}
+
+impl State {
+    fn roll(&self, roll: u64, p1_turn: bool) -> State {
+        if p1_turn {
+            State {
+                p1: self.p1.roll(roll),
+                p2: self.p2,
+            }
+        } else {
+            State {
+                p1: self.p1,
+                p2: self.p2.roll(roll),
+            }
+        }
+    }
+}
+
+fn wins(state: State, p1_turn: bool, cache: &mut HashMap<(State, bool), (u64, u64)>) -> (u64, u64) {
+    if cache.contains_key(&(state, p1_turn)) {
+        return cache[&(state, p1_turn)];
+    }
+    let (mut p1_tot, mut p2_tot) = (0, 0);
+    for (roll, weight) in DIRAC.iter() {
+        let new_state = state.roll(*roll, p1_turn);
+        if p1_turn && new_state.p1.score >= DIRAC_SCORE {
+            p1_tot += weight;
+        } else if !p1_turn && new_state.p2.score >= DIRAC_SCORE {
+            p2_tot += weight;
+        } else {
+            let (p1_wins, p2_wins) = wins(new_state, !p1_turn, cache);
+            p1_tot += weight * p1_wins;
+            p2_tot += weight * p2_wins;
+        }
+    }
+    cache.insert((state, p1_turn), (p1_tot, p2_tot));
+    (p1_tot, p2_tot)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    type Input<'a> = State;
+    type P1 = u64;
+    type P2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut lines = input.lines();
+        let mut player = || -> Result<Player, ParseError> {
+            let line = lines.next().ok_or_else(|| {
+                ParseError::at(input, &input[input.len()..], "expected two players")
+            })?;
+            let token = line.rsplit(' ').next().unwrap_or(line);
+            let pos = parse_at(input, token)?;
+            if !(1..=10).contains(&pos) {
+                return Err(ParseError::at(input, token, "positions go from 1 to 10"));
+            }
+            Ok(Player { pos, score: 0 })
+        };
+        Ok(State {
+            p1: player()?,
+            p2: player()?,
+        })
+    }
+
+    fn part1(_state: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(0)
+    }
+
+    fn part2(state: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        let num_wins = wins(*state, true, &mut HashMap::new());
+        Ok(max(num_wins.0, num_wins.1))
+    }
+}
@@ -1,6 +1,8 @@
+use aoc_2dmap::prelude::*;
 use aoc_prelude::*;
 
 use std::fmt::{Debug, Formatter};
+use std::ops::Index;
 
 lazy_static! {
     pub static ref PLANE_COMBOS: [[Dim; 3]; 6] = {
@@ -30,41 +32,13 @@ pub enum Dim {
     Z = 2,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-pub struct Point {
-    pub x: i64,
-    pub y: i64,
-    pub z: i64,
-}
-
-impl From<[i64; 3]> for Point {
-    fn from(t: [i64; 3]) -> Self {
-        Self {
-            x: t[0],
-            y: t[1],
-            z: t[2],
-        }
-    }
-}
+pub type Point = Pos3<i64>;
 
-impl Point {
-    pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
-        [self.x, self.y, self.z].into_iter()
-    }
-
-    pub fn get(&self, dim: Dim) -> i64 {
-        match dim {
-            Dim::X => self.x,
-            Dim::Y => self.y,
-            Dim::Z => self.z,
-        }
-    }
+impl Index<Dim> for Point {
+    type Output = i64;
 
-    pub fn as_plane(&self, dim: Dim) -> Plane {
-        Plane {
-            dim,
-            pos: self.get(dim),
-        }
+    fn index(&self, dim: Dim) -> &Self::Output {
+        &self[dim as usize]
     }
 }
 
@@ -72,9 +46,9 @@ impl ProjectTo<Plane> for Point {
     type Output = Point;
 
     fn project_to(&self, plane: Plane) -> Self::Output {
-        let mut coords = [self.x, self.y, self.z];
-        coords[plane.dim as usize] = plane.pos;
-        coords.into()
+        let mut projected = *self;
+        projected[plane.dim as usize] = plane.pos;
+        projected
     }
 }
 
@@ -84,6 +58,16 @@ pub struct Plane {
     pub pos: i64,
 }
 
+impl Plane {
+    /// The plane along `dim` going through `point`.
+    pub fn through(point: Point, dim: Dim) -> Self {
+        Plane {
+            dim,
+            pos: point[dim],
+        }
+    }
+}
+
 impl PartialEq for Plane {
     fn eq(&self, other: &Self) -> bool {
         self.dim == other.dim
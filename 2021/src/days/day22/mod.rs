@@ -2,10 +2,11 @@ mod geometry;
 mod parse;
 mod tests;
 
-use crate::geometry::*;
+use self::geometry::*;
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
 use aoc_prelude::*;
 
-use std::cmp::{max, min};
 use std::fmt::{Debug, Formatter};
 use std::ops::{Add, BitAnd, Sub};
 
@@ -29,7 +30,7 @@ impl Cube {
         .map(|(dims, (p0, p1, p2))| {
             dims.iter()
                 .zip([p0, p1, p2])
-                .map(|(&dim, point)| point.as_plane(dim))
+                .map(|(&dim, point)| Plane::through(point, dim))
                 .collect()
         })
     }
@@ -41,7 +42,7 @@ impl Cube {
         // we're subtracting from
         let common = self & bigger;
 
-        bigger.planes().into_iter().for_each(|planes| {
+        bigger.planes().for_each(|planes| {
             // we get a sequence of 3 different planes here
             // do moves in turn and record all partial results along
             // with the result of the final translation
@@ -69,8 +70,8 @@ impl ProjectTo<Plane> for Cube {
     type Output = Option<Cube>;
 
     fn project_to(&self, plane: Plane) -> Self::Output {
-        let bound_l = self.l.get(plane.dim);
-        let bound_o = self.o.get(plane.dim);
+        let bound_l = self.l[plane.dim];
+        let bound_o = self.o[plane.dim];
 
         if plane.pos > bound_l {
             // case 1
@@ -117,19 +118,7 @@ impl BitAnd for &Cube {
     type Output = Cube;
 
     fn bitand(self, rhs: Self) -> Self::Output {
-        (
-            [
-                max(self.o.x, rhs.o.x),
-                max(self.o.y, rhs.o.y),
-                max(self.o.z, rhs.o.z),
-            ],
-            [
-                min(self.l.x, rhs.l.x),
-                min(self.l.y, rhs.l.y),
-                min(self.l.z, rhs.l.z),
-            ],
-        )
-            .into()
+        (self.o.component_max(rhs.o), self.l.component_min(rhs.l)).into()
     }
 }
 
@@ -187,8 +176,8 @@ impl<P: Into<Point>, Q: Into<Point>> From<(P, Q)> for Cube {
         let a = pts.0.into();
         let b = pts.1.into();
         Cube {
-            o: [min(a.x, b.x), min(a.y, b.y), min(a.z, b.z)].into(),
-            l: [max(a.x, b.x), max(a.y, b.y), max(a.z, b.z)].into(),
+            o: a.component_min(b),
+            l: a.component_max(b),
         }
     }
 }
@@ -208,18 +197,30 @@ fn process_cubes<F: Fn(Cube) -> bool>(cubes: Vec<(Cube, String)>, accept: F) ->
     volume(proc)
 }
 
-aoc_2021::main! {
-    let cubes: Vec<_> = include_str!("../../inputs/day22.txt")
-        .lines()
-        .map(parse::process_line)
-        .collect();
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input<'a> = Vec<(Cube, String)>;
+    type P1 = usize;
+    type P2 = usize;
 
-    // Part 1
-    let world: Cube = ([-50, -50, -50], [50, 50, 50]).into();
-    let p1 = process_cubes(cubes.to_owned(), |cube| cube.intersects(&world));
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse::process_line(input, line))
+            .collect()
+    }
 
-    // Part 2
-    let p2 = process_cubes(cubes, |_| true);
+    fn part1(cubes: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let world: Cube = ([-50, -50, -50], [50, 50, 50]).into();
+        Ok(process_cubes(cubes.to_owned(), |cube| {
+            cube.intersects(&world)
+        }))
+    }
 
-    (p1, p2)
+    fn part2(cubes: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(process_cubes(cubes.to_owned(), |_| true))
+    }
 }
@@ -0,0 +1,32 @@
+use super::Cube;
+use crate::error::{parse_at, ParseError};
+use aoc_prelude::*;
+
+#[derive(Parser)]
+#[grammar = "parsers/day22-cubes.pest"]
+struct CubeParser;
+
+/// Parse `line`, a line of `input`.
+pub fn process_line(input: &str, line: &str) -> Result<(Cube, String), ParseError> {
+    let parsed = CubeParser::parse(Rule::cube, line)
+        .map_err(|err| ParseError::from(err).within(input, line))?
+        .next()
+        .unwrap();
+
+    let v: Vec<i64> = parsed
+        .to_owned()
+        .into_inner()
+        .filter(|r| r.as_rule() == Rule::number)
+        .map(|r| parse_at(input, r.as_str()))
+        .collect::<Result<_, _>>()?;
+
+    // the grammar guarantees a command and three pairs of bounds
+    let cmd = parsed
+        .into_inner()
+        .filter(|r| r.as_rule() == Rule::cmd)
+        .map(|r| r.as_str().to_owned())
+        .next()
+        .unwrap();
+
+    Ok((([v[0], v[2], v[4]], [v[1], v[3], v[5]]).into(), cmd))
+}
@@ -1,5 +1,5 @@
 #[cfg(test)]
-use crate::*;
+use super::*;
 
 #[test]
 fn contains() {
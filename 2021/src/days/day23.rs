@@ -0,0 +1,307 @@
+use std::convert::Infallible;
+use std::hash::Hash;
+
+use aoc_2dmap::prelude::*;
+use aoc_dijsktra::{Dijsktra, GameState, Transform};
+use aoc_prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum Tile {
+    Empty,
+    Wall,
+    Pod(u8),
+}
+
+impl Tile {
+    fn get_pod(self) -> Option<u8> {
+        match self {
+            Self::Pod(c) => Some(c),
+            _ => None,
+        }
+    }
+}
+
+type State = Map<Tile>;
+
+struct PodContext {
+    vis: Vec<(Pos, usize)>,
+    seen: HashSet<Pos>,
+    q: VecDeque<(Pos, usize)>,
+}
+
+impl PodContext {
+    fn new() -> Self {
+        let vis = Vec::with_capacity(100);
+        let seen = HashSet::<Pos>::with_capacity(100);
+        let q = VecDeque::with_capacity(100);
+        Self { vis, seen, q }
+    }
+
+    fn clear(&mut self) {
+        self.vis.clear();
+        self.seen.clear();
+        self.q.clear();
+    }
+}
+
+impl GameState<PodContext> for State {
+    type Steps = ArrayVec<Move, 64>;
+
+    /// True if all columns are solved
+    fn accept(&self, _cost: usize, _ctx: &mut PodContext) -> bool {
+        (0..=3).all(|idx| {
+            let c = (idx as u8) + b'A';
+            let c_num = room(c);
+            if c_num >= self.size.x {
+                false
+            } else {
+                self.col(c_num).skip(1).all(|&t| t == Tile::Pod(c))
+            }
+        })
+    }
+
+    /// Get all possible octopod moves
+    fn steps(&self, ctx: &mut PodContext) -> ArrayVec<Move, 64> {
+        self.iter()
+            .filter(|x| x.is_pod(self))
+            .flat_map(|from| {
+                let step_cost = [1, 10, 100, 1000][(from.get_byte(self) - b'A') as usize];
+                visible(self, from, ctx)
+                    .into_iter()
+                    .map(move |(to, steps)| Move {
+                        from,
+                        to,
+                        cost: step_cost * steps,
+                    })
+            })
+            .filter(|mv| mv.is_valid(self))
+            .collect()
+    }
+}
+
+struct Move {
+    from: Pos,
+    to: Pos,
+    cost: usize,
+}
+
+impl Transform<State> for Move {
+    fn cost(&self) -> usize {
+        self.cost
+    }
+
+    fn transform(&self, state: &State) -> State {
+        let mut new_map = (*state).clone();
+        new_map.swap(self.from, self.to);
+        new_map
+    }
+}
+
+impl Move {
+    #[inline(always)]
+    fn is_valid(&self, m: &State) -> bool {
+        if self.to.is_entrance() {
+            return false;
+        }
+
+        let c = self.from.get_byte(m);
+
+        if self.from.is_room() && self.to.is_hallway() {
+            // can only move out of our own room if any of the underlings are wrong
+            let c_num = room(c);
+            if self.from.x == c_num && c_num < m.size.x {
+                return m
+                    .col(c_num)
+                    .skip(self.from.y as usize - 1)
+                    .any(|&y| y != Tile::Pod(c));
+            }
+            return true;
+        }
+
+        if self.from.is_room() && self.to.is_room() && self.from.x != self.to.x {
+            return self.is_room_valid(c, m);
+        }
+
+        if self.from.is_hallway() && self.to.is_room() {
+            return self.is_room_valid(c, m);
+        }
+
+        false
+    }
+
+    fn is_room_valid(&self, c: u8, m: &State) -> bool {
+        if self.to.x != room(c) {
+            return false;
+        }
+
+        if self.to.x >= m.size.x {
+            false
+        } else {
+            let column: Vec<_> = m.col(self.to.x).copied().collect();
+
+            if (self.to.y as usize) < column.len() - 1 {
+                // trying to move into non-empty room, check for aliens
+                ((self.to.y + 1) as usize..column.len()).all(|y| column[y] == Tile::Pod(c))
+            } else {
+                // trying to move to bottom of room, check if empty
+                *column.last().unwrap() == Tile::Empty
+            }
+        }
+    }
+}
+
+trait PodPos {
+    fn is_empty(&self, m: &State) -> bool;
+    fn is_pod(&self, m: &State) -> bool;
+    fn get_byte(&self, m: &State) -> u8;
+    fn is_hallway(&self) -> bool;
+    fn is_room(&self) -> bool;
+    fn is_entrance(&self) -> bool;
+}
+
+impl PodPos for Pos {
+    fn is_empty(&self, m: &State) -> bool {
+        matches!(m.get(self), Some(Tile::Empty))
+    }
+
+    fn is_pod(&self, m: &State) -> bool {
+        matches!(m.get(self), Some(Tile::Pod(_)))
+    }
+
+    fn get_byte(&self, m: &State) -> u8 {
+        m.get(self).and_then(Tile::get_pod).unwrap()
+    }
+
+    fn is_hallway(&self) -> bool {
+        self.y == 0
+    }
+
+    fn is_room(&self) -> bool {
+        self.y >= 1 && [2, 4, 6, 8].contains(&self.x)
+    }
+
+    fn is_entrance(&self) -> bool {
+        self.is_hallway() && [2, 4, 6, 8].contains(&self.x)
+    }
+}
+
+/// Return the room index (column) for the given pod byte
+#[inline(always)]
+fn room(pod: u8) -> i32 {
+    [2, 4, 6, 8][(pod - b'A') as usize]
+}
+
+/// Generate all visible positions from the starting position
+fn visible(m: &State, start_pos: Pos, ctx: &mut PodContext) -> Vec<(Pos, usize)> {
+    ctx.clear();
+
+    ctx.q.push_back((start_pos, 0));
+
+    while let Some((pos, steps)) = ctx.q.pop_back() {
+        for neigh in pos.neighbors_simple() {
+            if neigh.is_empty(m) && !ctx.seen.contains(&neigh) {
+                ctx.vis.push((neigh, steps + 1));
+                ctx.q.push_back((neigh, steps + 1));
+                ctx.seen.insert(neigh);
+            }
+        }
+    }
+    ctx.vis.to_owned()
+}
+
+/// Lines folded into the burrow for part two.
+const UNFOLDED: [&str; 2] = ["  #D#C#B#A#", "  #D#B#A#C#"];
+
+/// Strip the burrow's outer walls so that the hallway sits on row 0,
+/// with rooms hanging below it at columns 2, 4, 6 and 8.
+fn burrow(input: &str, unfold: bool) -> Result<State, ParseError> {
+    let mut lines: Vec<_> = input.lines().skip(1).collect();
+    if unfold {
+        lines.splice(2..2, UNFOLDED);
+    }
+    lines.pop();
+    let grid = lines
+        .into_iter()
+        .map(|l| {
+            format!("{:<13}", l)
+                .chars()
+                .skip(1)
+                .take(11)
+                .collect::<String>()
+        })
+        .join("\n");
+    Ok(Map::from_grid(&grid)?)
+}
+
+/// Check for the usual burrow: walls, a hallway and two rows of rooms
+/// holding two amphipods of each kind.
+fn check_burrow(input: &str) -> Result<(), ParseError> {
+    let lines: Vec<_> = input.lines().collect();
+    if lines.len() != 5 {
+        return Err(ParseError::at(
+            input,
+            &input[input.len()..],
+            format!("expected 5 lines, found {}", lines.len()),
+        ));
+    }
+    for line in &lines {
+        if let Some(idx) = line.find(|c| !"#. ABCD".contains(c)) {
+            return Err(ParseError::at(input, &line[idx..], "unexpected character"));
+        }
+    }
+    for pod in "ABCD".chars() {
+        let count = input.matches(pod).count();
+        if count != 2 {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("expected two amphipods of type {}, found {}", pod, count),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn solve(map: &State) -> Result<usize, SolveError> {
+    map.clone()
+        .dijsktra(&mut PodContext::new())
+        .ok_or(SolveError::new("amphipods can't be organized"))
+}
+
+impl FromChar for Tile {
+    type Err = Infallible;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        Ok(match c {
+            '.' => Tile::Empty,
+            'A'..='D' => Tile::Pod(c as u8),
+            _ => Tile::Wall,
+        })
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    type Input<'a> = (State, State);
+    type P1 = usize;
+    type P2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        check_burrow(input)?;
+        Ok((burrow(input, false)?, burrow(input, true)?))
+    }
+
+    fn part1((folded, _): &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        solve(folded)
+    }
+
+    fn part2((_, unfolded): &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        solve(unfolded)
+    }
+}
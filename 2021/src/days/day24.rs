@@ -0,0 +1,109 @@
+use aoc_prelude::*;
+use regex::Regex;
+
+use crate::error::{parse_at, ParseError, SolveError};
+use crate::Solution;
+
+lazy_static! {
+    static ref PROG_REGEX: Regex = Regex::new(
+        r"inp w
+mul x 0
+add x z
+mod x 26
+div z (-?\d+)
+add x (-?\d+)
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y (-?\d+)
+mul y x
+add z y"
+    )
+    .unwrap();
+}
+
+fn grok_asm(input: &str) -> Result<Vec<[i64; 3]>, ParseError> {
+    let params = PROG_REGEX
+        .captures_iter(input)
+        .map(|captures| {
+            let param = |idx: usize| parse_at(input, captures.get(idx).unwrap().as_str());
+            let p0 = param(1)?;
+            if p0 != 1 && p0 != 26 {
+                let token = captures.get(1).unwrap().as_str();
+                return Err(ParseError::at(
+                    input,
+                    token,
+                    "expected 'div z 1' or 'div z 26'",
+                ));
+            }
+            Ok([p0, param(2)?, param(3)?])
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if params.len() != 14 {
+        return Err(ParseError::new(
+            1,
+            1,
+            format!("expected 14 digit checks, found {}", params.len()),
+        ));
+    }
+    Ok(params)
+}
+
+fn solve(params: &[[i64; 3]]) -> Result<(String, String), SolveError> {
+    let mut stack = VecDeque::new();
+    let mut min = [0; 14];
+    let mut max = [0; 14];
+    for (j, &[p0, p1, p2]) in params.iter().enumerate() {
+        match p0 {
+            1 => stack.push_back((j, p2)),
+
+            26 => {
+                let (i, c) = stack
+                    .pop_back()
+                    .ok_or(SolveError::new("more pops than pushes"))?;
+                let d = p1 + c;
+                let (i, j, d) = if d < 0 { (j, i, -d) } else { (i, j, d) };
+                if d > 8 {
+                    return Err(SolveError::new("no digits satisfy the checks"));
+                }
+                max[i] = 9 - d;
+                max[j] = 9;
+                min[i] = 1;
+                min[j] = 1 + d;
+            }
+            _ => unreachable!(),
+        }
+    }
+    let min = min.iter().join("");
+    let max = max.iter().join("");
+    Ok((max, min))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input<'a> = Vec<[i64; 3]>;
+    type P1 = String;
+    type P2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        grok_asm(input)
+    }
+
+    fn part1(params: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        Ok(solve(params)?.0)
+    }
+
+    fn part2(params: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok(solve(params)?.1)
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_2dmap::prelude::*;
+
+use crate::error::{ParseError, SolveError};
+use crate::Solution;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum Cuke {
+    Empty = 0,
+    South = 1,
+    East = 2,
+}
+
+impl Cuke {
+    fn is_empty(&self) -> bool {
+        matches!(self, Cuke::Empty)
+    }
+
+    fn heading(&self) -> Dir4 {
+        match self {
+            Self::East => Dir4::East,
+            Self::South => Dir4::South,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub type CukeMap = Map<Cuke>;
+
+/// Give up on herds that keep moving, since they may do so forever.
+const MAX_STEPS: usize = 100_000;
+
+/// What's at `pos` once `herd` moved, given what was where before.
+fn moved<F: Fn(Pos) -> Cuke>(herd: Cuke, pos: Pos, before: F) -> Cuke {
+    let offset = herd.heading().offset();
+    match before(pos) {
+        Cuke::Empty if before(pos - offset) == herd => herd,
+        cuke if cuke == herd && before(pos + offset).is_empty() => Cuke::Empty,
+        cuke => cuke,
+    }
+}
+
+impl FromChar for Cuke {
+    type Err = &'static str;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '>' => Ok(Self::East),
+            'v' => Ok(Self::South),
+            '.' => Ok(Self::Empty),
+            _ => Err("expected '>', 'v' or '.'"),
+        }
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input<'a> = CukeMap;
+    type P1 = usize;
+    type P2 = &'static str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        // cucumbers leaving at one edge come back at the opposite one
+        Ok(Map::from_grid(input)?.with_topology(Topology::Torus))
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Result<Self::P1, SolveError> {
+        let mut cukes = Automaton::new(map.clone());
+        // a step is the east facing herd moving, then the south facing one
+        cukes
+            .run_until_stable_within(MAX_STEPS, |pos, _, map| {
+                moved(Cuke::South, pos, |p| moved(Cuke::East, p, |q| map[q]))
+            })
+            .ok_or(SolveError::new(format!(
+                "sea cucumbers still move after {} steps",
+                MAX_STEPS
+            )))
+    }
+
+    fn part2(_map: &Self::Input<'_>) -> Result<Self::P2, SolveError> {
+        Ok("🕶️")
+    }
+}
@@ -0,0 +1,182 @@
+//! Errors shared by the days and the harness.
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use aoc_2dmap::prelude::GridError;
+use pest::error::LineColLocation;
+use pest::RuleType;
+
+use crate::InputError;
+
+/// Why and where (1-based line and column) a day's input failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new<M: Into<String>>(line: usize, column: usize, message: M) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Error located at `fragment`, which should be a slice of `input`.
+    /// Anything else is reported at the start of the input.
+    pub fn at<M: Into<String>>(input: &str, fragment: &str, message: M) -> Self {
+        let (line, column) = line_col(input, fragment);
+        Self::new(line, column, message)
+    }
+
+    /// Relocate an error from parsing `fragment` on its own to its position
+    /// in the whole `input`.
+    pub fn within(self, input: &str, fragment: &str) -> Self {
+        let (line, column) = line_col(input, fragment);
+        Self {
+            column: if self.line == 1 {
+                column + self.column - 1
+            } else {
+                self.column
+            },
+            line: line + self.line - 1,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<R: RuleType> From<pest::error::Error<R>> for ParseError {
+    fn from(err: pest::error::Error<R>) -> Self {
+        let (LineColLocation::Pos((line, column)) | LineColLocation::Span((line, column), _)) =
+            err.line_col;
+        Self::new(line, column, err.variant.message())
+    }
+}
+
+impl From<GridError> for ParseError {
+    fn from(err: GridError) -> Self {
+        Self::new(err.row, err.column, err.message)
+    }
+}
+
+/// Parse `token`, a slice of `input`, reporting its position on failure.
+pub fn parse_at<T>(input: &str, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token
+        .parse()
+        .map_err(|err| ParseError::at(input, token, format!("invalid value '{}': {}", token, err)))
+}
+
+fn line_col(input: &str, fragment: &str) -> (usize, usize) {
+    let offset = (fragment.as_ptr() as usize)
+        .checked_sub(input.as_ptr() as usize)
+        .filter(|&offset| offset <= input.len())
+        .unwrap_or(0);
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    (
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    )
+}
+
+/// A part that could not come up with an answer for its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError(pub String);
+
+impl SolveError {
+    pub fn new<M: Into<String>>(message: M) -> Self {
+        Self(message.into())
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Anything that can go wrong while running a day.
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse {
+        day: u8,
+        source: ParseError,
+    },
+    Solve {
+        day: u8,
+        part: u8,
+        source: SolveError,
+    },
+    /// The day panicked, which is a bug rather than a bad input.
+    Panic {
+        day: u8,
+        message: String,
+    },
+}
+
+impl Error {
+    /// Process exit code for this class of failure. 1 is left for wrong
+    /// answers and 2 for usage errors.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Self::Input(_) => 3,
+            Self::Parse { .. } => 4,
+            Self::Solve { .. } => 5,
+            Self::Panic { .. } => 6,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Input(err) => write!(f, "{}", err),
+            Self::Parse { day, source } => {
+                write!(f, "day {}: cannot parse input: {}", day, source)
+            }
+            Self::Solve { day, part, source } => {
+                write!(f, "day {} part {}: {}", day, part, source)
+            }
+            Self::Panic { day, message } => write!(f, "day {} panicked: {}", day, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Input(err) => Some(err),
+            Self::Parse { source, .. } => Some(source),
+            Self::Solve { source, .. } => Some(source),
+            Self::Panic { .. } => None,
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Self {
+        Self::Input(err)
+    }
+}
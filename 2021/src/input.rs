@@ -0,0 +1,79 @@
+//! Puzzle input resolution.
+//!
+//! A day's input is looked up, in order, from:
+//! 1. a file path passed as the first command line argument,
+//! 2. whatever gets piped into stdin (if it's not a terminal and not empty),
+//! 3. `dayNN.txt` inside the directory pointed to by `AOC_INPUTS`,
+//!    falling back to this crate's `inputs/` directory.
+use std::env;
+use std::error::Error;
+use std::ffi::OsString;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::PathBuf;
+
+/// Environment variable overriding the inputs directory.
+pub const INPUTS_ENV: &str = "AOC_INPUTS";
+
+#[derive(Debug)]
+pub enum InputError {
+    Read { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "cannot read input {}: {}", path.display(), source)
+            }
+            Self::Stdin(source) => write!(f, "cannot read input from stdin: {}", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read { source, .. } | Self::Stdin(source) => Some(source),
+        }
+    }
+}
+
+/// Load the input for the given day number, as the day binaries do.
+pub fn load(day: u8) -> Result<String, InputError> {
+    if let Some(path) = env::args_os().nth(1) {
+        return read_path(path.into());
+    }
+    if let Some(piped) = read_stdin()? {
+        return Ok(piped);
+    }
+    from_dir(day)
+}
+
+/// Load the input for the given day number from the inputs directory only.
+pub fn from_dir(day: u8) -> Result<String, InputError> {
+    read_path(inputs_dir().join(format!("day{:0>2}.txt", day)))
+}
+
+/// The directory holding `dayNN.txt` files.
+pub fn inputs_dir() -> PathBuf {
+    env::var_os(INPUTS_ENV)
+        .unwrap_or_else(|| OsString::from(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs")))
+        .into()
+}
+
+fn read_path(path: PathBuf) -> Result<String, InputError> {
+    fs::read_to_string(&path).map_err(|source| InputError::Read { path, source })
+}
+
+fn read_stdin() -> Result<Option<String>, InputError> {
+    let mut stdin = io::stdin();
+    if stdin.is_terminal() {
+        return Ok(None);
+    }
+    let mut buf = String::new();
+    stdin.read_to_string(&mut buf).map_err(InputError::Stdin)?;
+    Ok(Some(buf).filter(|s| !s.is_empty()))
+}
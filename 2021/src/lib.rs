@@ -1,15 +1,44 @@
+pub mod days;
+pub mod error;
+pub mod input;
+pub mod report;
+pub mod solution;
+pub mod timing;
+pub mod verify;
+
+pub use crate::error::{Error, ParseError, SolveError};
+pub use crate::input::InputError;
+pub use crate::solution::{solve, solve_parts, Answers, Day, Parts, Solution};
+pub use crate::timing::{measure, Summary, Timings};
+
 /// Macro for solution timing
 /// Credits: https://github.com/AxlLind/
+///
+/// Expands to a `main` that loads the day's input (see `input::load`)
+/// and solves it with the given `Solution` implementor, reporting the time
+/// spent parsing and in each part. Set `AOC_REPEAT` to run it several times
+/// and get the min/median/mean instead.
+///
+/// Failures are reported on stderr and exit with `Error::exit_code`.
 #[macro_export]
 macro_rules! main {
-  ($($body:tt)+) => {
-    fn main() {
-      let now = std::time::Instant::now();
-      let (p1,p2) = { $($body)+ };
-      let time = now.elapsed().as_millis();
-      println!("Part one: {}", p1);
-      println!("Part two: {}", p2);
-      println!("Time: {}ms", time);
-    }
-  }
+    ($solution:ty) => {
+        fn main() {
+            let repeat = $crate::timing::repeat_from_env().unwrap_or_else(|err| {
+                eprintln!("{}: {}", env!("CARGO_BIN_NAME"), err);
+                std::process::exit(2);
+            });
+            let run = || -> Result<_, $crate::Error> {
+                let input = $crate::input::load(<$solution as $crate::Solution>::DAY)?;
+                $crate::measure(repeat, || $crate::solve::<$solution>(&input))
+            };
+            let (answers, summary) = run().unwrap_or_else(|err| {
+                eprintln!("{}: {}", env!("CARGO_BIN_NAME"), err);
+                std::process::exit(err.exit_code());
+            });
+            println!("Part one: {}", answers.part1.unwrap_or_default());
+            println!("Part two: {}", answers.part2.unwrap_or_default());
+            println!("{}", summary);
+        }
+    };
 }
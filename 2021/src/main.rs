@@ -1,31 +1,242 @@
-use aoc_prelude::*;
+use std::any::Any;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::exit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use regex::Regex;
-use std::process::Command;
+use aoc_2021::days::DAYS;
+use aoc_2021::report::{Format, Record, Totals};
+use aoc_2021::verify::Manifest;
+use aoc_2021::{input, measure, timing, Day, Error, Parts};
 
-lazy_static! {
-    static ref TIME_REGEX: Regex = Regex::new(r"Time: (\d+)ms").unwrap();
+const USAGE: &str = "usage: aoc_2021 [DAYS...] [--skip DAYS] [--part 1|2] \
+                     [--format json|csv|table] [--repeat N] [--jobs N] [--verify]
+DAYS are day numbers or ranges, separated by spaces or commas: 3 7-9,12";
+
+struct Args {
+    /// `None` when no days were given, to run all of them.
+    days: Option<Vec<u8>>,
+    skip: Vec<u8>,
+    parts: Parts,
+    format: Format,
+    repeat: usize,
+    jobs: usize,
+    verify: bool,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut parsed = Args {
+        days: None,
+        skip: Vec::new(),
+        parts: Parts::Both,
+        format: Format::default(),
+        repeat: timing::repeat_from_env()?,
+        jobs: 1,
+        verify: false,
+    };
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg, None),
+        };
+        let mut value = || {
+            inline
+                .clone()
+                .or_else(|| args.next())
+                .ok_or(format!("missing value for {}", flag))
+        };
+        match flag.as_str() {
+            "--skip" => parsed.skip.extend(parse_days(&value()?)?),
+            "--part" => parsed.parts = value()?.parse()?,
+            "--format" => parsed.format = value()?.parse()?,
+            "--repeat" => parsed.repeat = timing::parse_repeat(&value()?)?,
+            "--jobs" => {
+                let value = value()?;
+                parsed.jobs = value.parse().ok().filter(|&n| n > 0).ok_or(format!(
+                    "invalid job count '{}', expected a positive number",
+                    value
+                ))?
+            }
+            "--verify" if inline.is_none() => parsed.verify = true,
+            _ if !flag.starts_with('-') && inline.is_none() => {
+                let days = parse_days(&flag)?;
+                parsed.days.get_or_insert_with(Vec::new).extend(days)
+            }
+            _ => return Err(format!("unexpected argument '{}'", flag)),
+        }
+    }
+    Ok(parsed)
+}
+
+/// Parse a list of days like `3,7-9`.
+fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let day = |s: &str| {
+        s.parse()
+            .ok()
+            .filter(|d| (1..=25).contains(d))
+            .ok_or(format!("invalid day '{}', expected 1-25", s))
+    };
+    let mut days = Vec::new();
+    for item in spec.split(',').filter(|s| !s.is_empty()) {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (day(from)?, day(to)?);
+                if from > to {
+                    return Err(format!(
+                        "invalid range '{}', {} comes after {}",
+                        item, from, to
+                    ));
+                }
+                days.extend(from..=to)
+            }
+            None => days.push(day(item)?),
+        }
+    }
+    if days.is_empty() {
+        return Err(format!("no days in '{}'", spec));
+    }
+    Ok(days)
+}
+
+impl Args {
+    /// Days to run: the requested ones (or all of them) minus the skipped ones.
+    fn selected(&self) -> Vec<&'static Day> {
+        DAYS.iter()
+            .filter(|d| self.days.as_ref().is_none_or(|days| days.contains(&d.day)))
+            .filter(|d| !self.skip.contains(&d.day))
+            .collect()
+    }
 }
 
-fn extract_time(s: &str) -> u32 {
-    let capture = TIME_REGEX.captures_iter(s).next().unwrap();
-    capture[1].parse().unwrap()
+/// Solve a day, returning its record along with the time spent on all runs.
+fn run_day(day: &Day, args: &Args) -> Result<(Record, Duration), Error> {
+    let input = input::from_dir(day.day)?;
+    let (mut answers, summary) = measure(args.repeat, || (day.solve)(&input, args.parts))?;
+    answers.timings = summary.median;
+    let elapsed = summary.mean.total() * summary.runs as u32;
+    Ok((Record::solved(day.day, answers), elapsed))
+}
+
+/// The message a panic was raised with, when it's a string.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload
+            .downcast_ref::<String>()
+            .cloned()
+            .unwrap_or_else(|| "unknown panic".to_string()),
+    }
+}
+
+/// Run the given days on `args.jobs` threads, returning the results in order.
+fn run_all(days: &[&Day], args: &Args) -> Vec<Result<(Record, Duration), Error>> {
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<_> = thread::scope(|s| {
+        let workers: Vec<_> = (0..args.jobs)
+            .map(|_| {
+                s.spawn(|| {
+                    let mut done = Vec::new();
+                    while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        // A panicking day only fails itself, the others still run.
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| run_day(day, args)))
+                            .unwrap_or_else(|payload| {
+                                Err(Error::Panic {
+                                    day: day.day,
+                                    message: panic_message(payload.as_ref()),
+                                })
+                            });
+                        done.push((day.day, result));
+                    }
+                    done
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("worker panicked"))
+            .collect()
+    });
+    results.sort_by_key(|(day, _)| *day);
+    results.into_iter().map(|(_, result)| result).collect()
 }
 
 fn main() {
-    let dot_dir = std::env::current_exe()
-        .map(|x| x.parent().unwrap().to_owned())
-        .expect("cannot get current exe");
-
-    let total_time = (1..=25)
-        .map(|day_num| {
-            let cmd = Command::new(dot_dir.join(format!("day{:0>2}", day_num)))
-                .output()
-                .unwrap();
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            println!("Day {}:\n{}", day_num, output);
-            extract_time(&output)
+    let args = parse_args().unwrap_or_else(|err| {
+        eprintln!("{}\n{}", err, USAGE);
+        exit(2);
+    });
+    let manifest = args.verify.then(|| {
+        Manifest::load().unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            exit(2);
         })
-        .sum::<u32>();
-    println!("Total time: {}ms", total_time);
+    });
+
+    let start = Instant::now();
+    let days = args.selected();
+    let results = run_all(&days, &args);
+    let mut totals = Totals {
+        wall: start.elapsed(),
+        days: Duration::ZERO,
+        jobs: args.jobs,
+    };
+
+    // Missing inputs are skipped unless verifying, anything else fails the run
+    // once all days are done.
+    let mut failure = None;
+    let mut records = Vec::with_capacity(results.len());
+    for (day, result) in days.iter().zip(results) {
+        match result {
+            Ok((record, elapsed)) => {
+                totals.days += elapsed;
+                records.push(record);
+            }
+            Err(Error::Input(err)) if !args.verify => eprintln!("Day {}: {}", day.day, err),
+            Err(Error::Input(err)) => {
+                eprintln!("Day {}: {}", day.day, err);
+                failure.get_or_insert(Error::Input(err).exit_code());
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                failure.get_or_insert(err.exit_code());
+                records.push(Record::failed(day.day, err));
+            }
+        }
+    }
+
+    args.format
+        .write(&mut io::stdout().lock(), &records, &totals)
+        .expect("cannot write results");
+    if args.format != Format::Table {
+        eprintln!("{}", totals);
+    }
+
+    if let Some(manifest) = manifest {
+        let mut failed = false;
+        // Failed days already fail the run.
+        for record in records.iter().filter(|r| r.error.is_none()) {
+            match manifest.get(record.day) {
+                Some(expected) => {
+                    for mismatch in expected.check(record.day, &record.answers) {
+                        eprintln!("{}", mismatch);
+                        failed = true;
+                    }
+                }
+                None => {
+                    eprintln!("Day {}: no expected answers", record.day);
+                    failed = true;
+                }
+            }
+        }
+        if failed {
+            failure.get_or_insert(1);
+        }
+    }
+    if let Some(code) = failure {
+        exit(code);
+    }
 }
@@ -0,0 +1,265 @@
+//! Machine (and human) readable output of the runner's results.
+use std::fmt::{Display, Formatter};
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::Answers;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+    #[default]
+    Table,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            "table" => Ok(Self::Table),
+            _ => Err(format!("unknown format '{}', expected json|csv|table", s)),
+        }
+    }
+}
+
+/// Outcome of running a single day.
+pub struct Record {
+    pub day: u8,
+    pub answers: Answers,
+    /// Why the day failed, naming the day, in which case it has no answers.
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn solved(day: u8, answers: Answers) -> Self {
+        Self {
+            day,
+            answers,
+            error: None,
+        }
+    }
+
+    pub fn failed<E: Display>(day: u8, error: E) -> Self {
+        Self {
+            day,
+            answers: Answers::default(),
+            error: Some(error.to_string()),
+        }
+    }
+}
+
+/// Time taken by the whole run, as opposed to the sum of the days' times.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Totals {
+    pub wall: Duration,
+    /// Sum of the days' elapsed times, which counts time spent waiting for a
+    /// core too when there are more jobs than cores.
+    pub days: Duration,
+    pub jobs: usize,
+}
+
+impl Display for Totals {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Total time: {:?} wall, {:?} sum of day times ({} job{})",
+            self.wall,
+            self.days,
+            self.jobs,
+            if self.jobs == 1 { "" } else { "s" }
+        )
+    }
+}
+
+impl Format {
+    /// Only the table includes the totals, to keep json and csv one record per day.
+    pub fn write<W: Write>(
+        &self,
+        out: &mut W,
+        records: &[Record],
+        totals: &Totals,
+    ) -> io::Result<()> {
+        match self {
+            Self::Json => write_json(out, records),
+            Self::Csv => write_csv(out, records),
+            Self::Table => write_table(out, records, totals),
+        }
+    }
+}
+
+fn write_json<W: Write>(out: &mut W, records: &[Record]) -> io::Result<()> {
+    writeln!(out, "[")?;
+    for (idx, record) in records.iter().enumerate() {
+        let sep = if idx + 1 < records.len() { "," } else { "" };
+        let t = record.answers.timings;
+        writeln!(
+            out,
+            r#"  {{"day": {}, "part1": {}, "part2": {}, "parse_ns": {}, "part1_ns": {}, "part2_ns": {}, "error": {}}}{}"#,
+            record.day,
+            Json(record.answers.part1.as_deref()),
+            Json(record.answers.part2.as_deref()),
+            t.parse.as_nanos(),
+            t.part1.as_nanos(),
+            t.part2.as_nanos(),
+            Json(record.error.as_deref()),
+            sep
+        )?;
+    }
+    writeln!(out, "]")
+}
+
+fn write_csv<W: Write>(out: &mut W, records: &[Record]) -> io::Result<()> {
+    writeln!(out, "day,part1,part2,parse_ns,part1_ns,part2_ns,error")?;
+    for record in records {
+        let t = record.answers.timings;
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            record.day,
+            Csv(record.answers.part1.as_deref().unwrap_or_default()),
+            Csv(record.answers.part2.as_deref().unwrap_or_default()),
+            t.parse.as_nanos(),
+            t.part1.as_nanos(),
+            t.part2.as_nanos(),
+            Csv(record.error.as_deref().unwrap_or_default())
+        )?;
+    }
+    Ok(())
+}
+
+/// Multi-line answers (e.g. day 13's letters) spill into continuation rows,
+/// parts that weren't solved show up as `-`. Failed days are listed with
+/// their errors under the table.
+fn write_table<W: Write>(out: &mut W, records: &[Record], totals: &Totals) -> io::Result<()> {
+    let header = [
+        "Day",
+        "Part one",
+        "Part two",
+        "Parse time",
+        "Part one time",
+        "Part two time",
+    ];
+    let rows: Vec<[String; 6]> = records.iter().flat_map(table_rows).collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |out: &mut W, row: &[&str]| -> io::Result<()> {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        writeln!(out, "{}", cells.join(" | ").trim_end())
+    };
+
+    print_row(out, &header)?;
+    let rule: Vec<_> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    writeln!(out, "{}", rule.join("-+-"))?;
+    for row in &rows {
+        print_row(out, &row.each_ref().map(String::as_str))?;
+    }
+
+    let failed: Vec<_> = records.iter().filter(|r| r.error.is_some()).collect();
+    if !failed.is_empty() {
+        writeln!(out)?;
+    }
+    for record in failed {
+        writeln!(out, "{}", record.error.as_deref().unwrap_or_default())?;
+    }
+
+    writeln!(out, "\n{}", totals)
+}
+
+fn table_rows(record: &Record) -> Vec<[String; 6]> {
+    let lines = |answer: &Option<String>| -> Vec<String> {
+        match answer {
+            Some(answer) => answer
+                .trim_start_matches('\n')
+                .lines()
+                .map(str::to_string)
+                .collect(),
+            None => vec!["-".to_string()],
+        }
+    };
+    let time = |answer: &Option<String>, time| match answer {
+        Some(_) => format!("{:?}", time),
+        None => "-".to_string(),
+    };
+    let (part1, part2) = (lines(&record.answers.part1), lines(&record.answers.part2));
+    let t = record.answers.timings;
+
+    (0..part1.len().max(part2.len()).max(1))
+        .map(|idx| {
+            let cell = |lines: &[String]| lines.get(idx).cloned().unwrap_or_default();
+            if idx == 0 {
+                [
+                    record.day.to_string(),
+                    cell(&part1),
+                    cell(&part2),
+                    match record.error {
+                        Some(_) => "-".to_string(),
+                        None => format!("{:?}", t.parse),
+                    },
+                    time(&record.answers.part1, t.part1),
+                    time(&record.answers.part2, t.part2),
+                ]
+            } else {
+                [
+                    String::new(),
+                    cell(&part1),
+                    cell(&part2),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                ]
+            }
+        })
+        .collect()
+}
+
+/// A quoted and escaped JSON string, or `null`.
+struct Json<'a>(Option<&'a str>);
+
+impl Display for Json<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let Some(s) = self.0 else {
+            return write!(f, "null");
+        };
+        write!(f, "\"")?;
+        for c in s.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                '\n' => write!(f, "\\n")?,
+                '\r' => write!(f, "\\r")?,
+                '\t' => write!(f, "\\t")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{}", c)?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+/// A CSV field, quoted only when it needs to be.
+struct Csv<'a>(&'a str);
+
+impl Display for Csv<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.contains([',', '"', '\n', '\r']) {
+            write!(f, "\"{}\"", self.0.replace('"', "\"\""))
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::error::{Error, ParseError, SolveError};
+use crate::timing::{timed, Timings};
+
+/// A day's puzzle, split into parsing and the two parts.
+///
+/// Both parts get a shared reference to the parsed input, so any work they
+/// have in common can be done once in `parse`.
+pub trait Solution {
+    /// Day of the advent calendar (1-25).
+    const DAY: u8;
+
+    type Input<'a>;
+    type P1: Display;
+    type P2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> Result<Self::P1, SolveError>;
+    fn part2(input: &Self::Input<'_>) -> Result<Self::P2, SolveError>;
+}
+
+/// Which parts of a day to solve.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    One,
+    Two,
+}
+
+impl Parts {
+    pub fn part1(self) -> bool {
+        self != Self::Two
+    }
+
+    pub fn part2(self) -> bool {
+        self != Self::One
+    }
+}
+
+impl FromStr for Parts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("unknown part '{}', expected 1 or 2", s)),
+        }
+    }
+}
+
+/// Rendered answers of a day, for the parts that were solved.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub timings: Timings,
+}
+
+/// Parse the input and solve both parts of the given `Solution`.
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, Error> {
+    solve_parts::<S>(input, Parts::Both)
+}
+
+/// Parse the input and solve the selected parts of the given `Solution`.
+pub fn solve_parts<S: Solution>(input: &str, parts: Parts) -> Result<Answers, Error> {
+    let (parsed, parse) = timed(|| S::parse(input));
+    let parsed = parsed.map_err(|source| Error::Parse {
+        day: S::DAY,
+        source,
+    })?;
+    let (part1, part1_time) = timed_part::<S, _>(1, parts.part1(), || S::part1(&parsed))?;
+    let (part2, part2_time) = timed_part::<S, _>(2, parts.part2(), || S::part2(&parsed))?;
+    Ok(Answers {
+        part1,
+        part2,
+        timings: Timings {
+            parse,
+            part1: part1_time,
+            part2: part2_time,
+        },
+    })
+}
+
+fn timed_part<S, P>(
+    part: u8,
+    selected: bool,
+    f: impl FnOnce() -> Result<P, SolveError>,
+) -> Result<(Option<String>, Duration), Error>
+where
+    S: Solution,
+    P: Display,
+{
+    if !selected {
+        return Ok((None, Duration::ZERO));
+    }
+    let (answer, time) = timed(f);
+    let answer = answer.map_err(|source| Error::Solve {
+        day: S::DAY,
+        part,
+        source,
+    })?;
+    Ok((Some(answer.to_string()), time))
+}
+
+/// Type-erased registry entry, so days can be stored and run side by side.
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str, Parts) -> Result<Answers, Error>,
+}
+
+impl Day {
+    pub const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solve_parts::<S>,
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt::{Display, Formatter};
+use std::time::{Duration, Instant};
+
+use crate::{Answers, Error};
+
+/// Time spent in each stage of a `Solution`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} (parse: {:?}, part one: {:?}, part two: {:?})",
+            self.total(),
+            self.parse,
+            self.part1,
+            self.part2
+        )
+    }
+}
+
+/// Per-stage statistics over repeated runs.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct Summary {
+    pub runs: usize,
+    pub min: Timings,
+    pub median: Timings,
+    pub mean: Timings,
+}
+
+impl Summary {
+    pub fn of(samples: &[Timings]) -> Self {
+        let stage = |f: fn(&Timings) -> Duration| {
+            let mut durations: Vec<_> = samples.iter().map(f).collect();
+            durations.sort_unstable();
+            durations
+        };
+        let (parse, part1, part2) = (stage(|t| t.parse), stage(|t| t.part1), stage(|t| t.part2));
+
+        let pick = |f: fn(&[Duration]) -> Duration| Timings {
+            parse: f(&parse),
+            part1: f(&part1),
+            part2: f(&part2),
+        };
+        Self {
+            runs: samples.len(),
+            min: pick(|d| d.first().copied().unwrap_or_default()),
+            median: pick(|d| d.get(d.len() / 2).copied().unwrap_or_default()),
+            mean: pick(|d| d.iter().sum::<Duration>() / d.len().max(1) as u32),
+        }
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.runs <= 1 {
+            return write!(f, "Time: {}", self.median);
+        }
+        writeln!(f, "Time (median of {}): {}", self.runs, self.median)?;
+        writeln!(f, "Time (min): {}", self.min)?;
+        write!(f, "Time (mean): {}", self.mean)
+    }
+}
+
+/// Run `f` `repeat` times (at least once), returning the last answers along
+/// with timing statistics over all runs. Stops at the first error.
+pub fn measure<F>(repeat: usize, f: F) -> Result<(Answers, Summary), Error>
+where
+    F: Fn() -> Result<Answers, Error>,
+{
+    let mut samples = Vec::with_capacity(repeat);
+    let mut answers = f()?;
+    samples.push(answers.timings);
+    for _ in 1..repeat {
+        answers = f()?;
+        samples.push(answers.timings);
+    }
+    Ok((answers, Summary::of(&samples)))
+}
+
+pub(crate) fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let now = Instant::now();
+    let res = f();
+    (res, now.elapsed())
+}
+
+/// Environment variable setting how many times each day is run.
+pub const REPEAT_ENV: &str = "AOC_REPEAT";
+
+/// The repeat count from `AOC_REPEAT`, defaulting to a single run.
+pub fn repeat_from_env() -> Result<usize, String> {
+    match std::env::var(REPEAT_ENV) {
+        Ok(value) => parse_repeat(&value),
+        Err(_) => Ok(1),
+    }
+}
+
+pub fn parse_repeat(value: &str) -> Result<usize, String> {
+    match value.parse() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!(
+            "invalid repeat count '{}', expected a positive number",
+            value
+        )),
+    }
+}
@@ -0,0 +1,156 @@
+//! Checking computed answers against a manifest of known good ones.
+//!
+//! The manifest is `answers.toml` in the inputs directory, with a table per
+//! day and the answers as strings or integers:
+//!
+//! ```toml
+//! [day01]
+//! part1 = 1292
+//! part2 = "1262"
+//! ```
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use toml::{Table, Value};
+
+use crate::{input, Answers};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug)]
+pub enum ManifestError {
+    Read { path: PathBuf, source: io::Error },
+    Parse(toml::de::Error),
+    Invalid(String),
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "cannot read answers {}: {}", path.display(), source)
+            }
+            Self::Parse(err) => write!(f, "cannot parse {}: {}", ANSWERS_FILE, err),
+            Self::Invalid(msg) => write!(f, "invalid {}: {}", ANSWERS_FILE, msg),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            Self::Parse(err) => Some(err),
+            Self::Invalid(_) => None,
+        }
+    }
+}
+
+/// Expected answers of a single day; either part may be left out.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// A solved part whose answer differs from the expected one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {} part {}: expected {}, got {}",
+            self.day, self.part, self.expected, self.actual
+        )
+    }
+}
+
+impl Expected {
+    pub fn check(&self, day: u8, answers: &Answers) -> Vec<Mismatch> {
+        [
+            (1, &self.part1, &answers.part1),
+            (2, &self.part2, &answers.part2),
+        ]
+        .into_iter()
+        .filter_map(|(part, expected, actual)| {
+            let (expected, actual) = (expected.as_ref()?, actual.as_ref()?);
+            (expected.trim() != actual.trim()).then(|| Mismatch {
+                day,
+                part,
+                expected: expected.clone(),
+                actual: actual.clone(),
+            })
+        })
+        .collect()
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Manifest(BTreeMap<u8, Expected>);
+
+impl Manifest {
+    /// Load `answers.toml` from the inputs directory.
+    pub fn load() -> Result<Self, ManifestError> {
+        let path = input::inputs_dir().join(ANSWERS_FILE);
+        let content =
+            fs::read_to_string(&path).map_err(|source| ManifestError::Read { path, source })?;
+        Self::parse(&content)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, ManifestError> {
+        let table: Table = content.parse().map_err(ManifestError::Parse)?;
+        let mut days = BTreeMap::new();
+
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|n| n.parse::<u8>().ok())
+                .filter(|n| (1..=25).contains(n))
+                .ok_or_else(|| ManifestError::Invalid(format!("unknown day '{}'", key)))?;
+            let Value::Table(parts) = value else {
+                return Err(ManifestError::Invalid(format!("'{}' is not a table", key)));
+            };
+
+            let mut expected = Expected::default();
+            for (part, answer) in parts {
+                let answer = match answer {
+                    Value::String(s) => s,
+                    Value::Integer(n) => n.to_string(),
+                    _ => {
+                        return Err(ManifestError::Invalid(format!(
+                            "{}.{} must be a string or an integer",
+                            key, part
+                        )))
+                    }
+                };
+                match part.as_str() {
+                    "part1" => expected.part1 = Some(answer),
+                    "part2" => expected.part2 = Some(answer),
+                    _ => {
+                        return Err(ManifestError::Invalid(format!(
+                            "unknown part '{}.{}'",
+                            key, part
+                        )))
+                    }
+                }
+            }
+            days.insert(day, expected);
+        }
+        Ok(Self(days))
+    }
+
+    pub fn get(&self, day: u8) -> Option<&Expected> {
+        self.0.get(&day)
+    }
+}
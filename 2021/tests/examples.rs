@@ -0,0 +1,129 @@
+//! The worked examples from the puzzle texts, run end to end through every
+//! day so that `cargo test` doesn't need the private inputs.
+use aoc_2021::days::*;
+use aoc_2021::{solve_parts, Error, Parts, Solution};
+use aoc_2dmap::prelude::Pos;
+
+/// Solve `input` and compare with the expected answers; a part without an
+/// expected answer isn't solved at all.
+fn check<S: Solution>(input: &str, part1: Option<String>, part2: Option<String>) {
+    let parts = match (&part1, &part2) {
+        (Some(_), None) => Parts::One,
+        (None, Some(_)) => Parts::Two,
+        _ => Parts::Both,
+    };
+    let answers = solve_parts::<S>(input, parts).unwrap_or_else(|err| panic!("{}", err));
+    assert_eq!(answers.part1, part1, "day {} part one", S::DAY);
+    assert_eq!(answers.part2, part2, "day {} part two", S::DAY);
+}
+
+macro_rules! answer {
+    (_) => {
+        None
+    };
+    ($answer:tt) => {
+        Some($answer.to_string())
+    };
+}
+
+/// `name: DayNN, input => part one, part two;` with `_` for no answer.
+macro_rules! examples {
+    ($($name:ident: $day:ty, $input:expr => $part1:tt, $part2:tt;)+) => {
+        $(
+            #[test]
+            fn $name() {
+                check::<$day>($input, answer!($part1), answer!($part2));
+            }
+        )+
+    };
+}
+
+macro_rules! example {
+    ($file:literal) => {
+        include_str!(concat!("examples/", $file, ".txt"))
+    };
+}
+
+// Part two renders the folded paper, starting on a new line.
+const DAY13_SQUARE: &str =
+    concat!("\n", "#####\n", "#   #\n", "#   #\n", "#   #\n", "#####\n", "     \n", "     ");
+
+examples! {
+    day01: Day01, example!("day01") => 7, 5;
+    day02: Day02, example!("day02") => 150, 900;
+    day03: Day03, example!("day03") => 198, 230;
+    // The scrubber rating gets down to numbers which all share the next bit.
+    day03_shared_bit: Day03, "11\n10\n" => 2, 6;
+    day03_without_last: Day03, example!("day03").trim_end().rsplit_once('\n').unwrap().0 => 198, 345;
+    day04: Day04, example!("day04") => 4512, 1924;
+    day05: Day05, example!("day05") => 5, 12;
+    day06: Day06, example!("day06") => 5934, 26984457539_u64;
+    day07: Day07, example!("day07") => 37, 168;
+    day08: Day08, example!("day08") => 26, 61229;
+    day09: Day09, example!("day09") => 15, 1134;
+    day10: Day10, example!("day10") => 26397, 288957;
+    day11: Day11, example!("day11") => 1656, 195;
+    day12: Day12, example!("day12") => 10, 36;
+    day12_larger: Day12, example!("day12-larger") => 19, 103;
+    day13: Day13, example!("day13") => 17, DAY13_SQUARE;
+    day14: Day14, example!("day14") => 1588, 2188189693529_u64;
+    day15: Day15, example!("day15") => 40, 315;
+    day16: Day16, example!("day16") => 20, 1;
+    day16_literal: Day16, "8A004A801A8002F478" => 16, _;
+    day16_sum: Day16, "C200B40A82" => _, 3;
+    day16_max: Day16, "CE00C43D881120" => _, 9;
+    day16_equal: Day16, "9C005AC2A8F0" => _, 0;
+    day17: Day17, example!("day17") => 45, 112;
+    day18: Day18, example!("day18") => 4140, 3993;
+    day19: Day19, example!("day19") => 79, 3621;
+    day20: Day20, example!("day20") => 35, 3351;
+    // Part one (the deterministic die) isn't implemented.
+    day21: Day21, example!("day21") => _, 444356092776315_u64;
+    day22: Day22, example!("day22") => 39, 39;
+    day23: Day23, example!("day23") => 12521, 44169;
+    // Day 24 has no example; the answers only exist for a puzzle input.
+    day25: Day25, example!("day25") => 58, "🕶️";
+}
+
+#[test]
+fn day15_route() {
+    let map = Day15::parse(example!("day15")).unwrap();
+    let (risk, route) = day15::route(&map).unwrap();
+    assert_eq!(route.first(), Some(&Pos::default()));
+    assert_eq!(route.last(), Some(&(map.size + (-1, -1).into())));
+    for step in route.windows(2) {
+        assert_eq!(step[0].manhattan(step[1]), 1, "{:?} isn't a step", step);
+    }
+    assert_eq!(route[1..].iter().map(|&p| map[p]).sum::<usize>(), risk);
+    assert_eq!(risk, 40);
+}
+
+// The input parses, it's the scanners that can't be put together.
+#[test]
+fn day19_no_overlap_is_a_solve_failure() {
+    let input = "--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n1,2,3\n";
+    let err = solve_parts::<Day19>(input, Parts::Two).unwrap_err();
+    assert!(matches!(err, Error::Solve { part: 2, .. }), "{}", err);
+    assert_eq!(err.exit_code(), 5);
+}
+
+/// `name: DayNN, input;` for inputs that must be rejected rather than panic.
+macro_rules! rejects {
+    ($($name:ident: $day:ty, $input:expr;)+) => {
+        $(
+            #[test]
+            fn $name() {
+                assert!(solve_parts::<$day>($input, Parts::Both).is_err());
+            }
+        )+
+    };
+}
+
+rejects! {
+    day03_blank_lines: Day03, "\n\n";
+    day03_too_wide: Day03, concat!("1010101010101010101010101010101010101010101010101010101010101010", "1\n");
+    day16_truncated_bit_count: Day16, "9C014108";
+    day16_truncated_literal: Day16, "380060";
+    day19_no_overlap: Day19, "--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n1,2,3\n";
+    day25_never_settles: Day25, ">.";
+}
@@ -0,0 +1,89 @@
+use aoc_2dmap::prelude::*;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+const SIZE: i32 = 100;
+
+fn grid() -> Map<u32> {
+    Map::new((SIZE, SIZE), 0..(SIZE * SIZE) as u32)
+}
+
+fn map_get(c: &mut Criterion) {
+    let map = grid();
+    let positions: Vec<_> = (-1..=SIZE)
+        .flat_map(|y| (-1..=SIZE).map(move |x| Pos { x, y }))
+        .collect();
+
+    let mut group = c.benchmark_group("map");
+    group.bench_function("get", |b| {
+        b.iter(|| {
+            positions
+                .iter()
+                .filter_map(|&p| map.get(black_box(p)))
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("get_ref", |b| {
+        b.iter(|| {
+            positions
+                .iter()
+                .filter_map(|&p| map.get_ref(black_box(p)))
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("index", |b| {
+        b.iter(|| map.iter().map(|p| map[black_box(p)]).sum::<u32>())
+    });
+    group.finish();
+}
+
+/// Sum the in-bounds neighbours of every tile, one benchmark per iterator.
+macro_rules! bench_neighbors {
+    ($group:expr, $map:expr, $($neighbors:ident),+) => {
+        $(
+            $group.bench_function(stringify!($neighbors), |b| {
+                b.iter(|| {
+                    $map.iter()
+                        .flat_map(|p| black_box(p).$neighbors())
+                        .filter_map(|p| $map.get(p))
+                        .sum::<u32>()
+                })
+            });
+        )+
+    };
+}
+
+fn pos_neighbors(c: &mut Criterion) {
+    let map = grid();
+
+    let mut group = c.benchmark_group("neighbors");
+    bench_neighbors!(
+        group,
+        map,
+        neighbors_simple,
+        neighbors_rdlu,
+        neighbors_simple_inclusive,
+        neighbors_diag,
+        neighbors_diag_inclusive,
+        neighbors_only_diag
+    );
+    group.bench_function("map_neighbors4", |b| {
+        b.iter(|| {
+            map.iter()
+                .flat_map(|p| map.neighbors4(black_box(p)))
+                .map(|(_, &tile)| tile)
+                .sum::<u32>()
+        })
+    });
+    group.bench_function("map_neighbors8", |b| {
+        b.iter(|| {
+            map.iter()
+                .flat_map(|p| map.neighbors8(black_box(p)))
+                .map(|(_, &tile)| tile)
+                .sum::<u32>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, map_get, pos_neighbors);
+criterion_main!(benches);
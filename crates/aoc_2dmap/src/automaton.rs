@@ -0,0 +1,137 @@
+use crate::map::Map;
+use crate::pos::Pos;
+
+/// Cellular automaton over a map. Every generation, each tile is replaced
+/// with what the rule makes of it, its position and the previous generation.
+#[derive(Clone)]
+pub struct Automaton<T> {
+    map: Map<T>,
+    next: Vec<T>,
+    generation: usize,
+}
+
+impl<T: PartialEq> Automaton<T> {
+    pub fn new(map: Map<T>) -> Self {
+        Self {
+            next: Vec::with_capacity(map.get_tiles().len()),
+            map,
+            generation: 0,
+        }
+    }
+
+    /// The current generation.
+    pub fn map(&self) -> &Map<T> {
+        &self.map
+    }
+
+    /// The current generation, for changes made outside of the rules.
+    pub fn map_mut(&mut self) -> &mut Map<T> {
+        &mut self.map
+    }
+
+    pub fn into_map(self) -> Map<T> {
+        self.map
+    }
+
+    /// Number of generations stepped so far.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Step a single generation, returning how many tiles changed.
+    pub fn step<F>(&mut self, mut rule: F) -> usize
+    where
+        F: FnMut(Pos, &T, &Map<T>) -> T,
+    {
+        let mut changed = 0;
+        self.next.clear();
+        for (pos, tile) in self.map.iter().zip(self.map.get_tiles()) {
+            let next = rule(pos, tile, &self.map);
+            changed += (next != *tile) as usize;
+            self.next.push(next);
+        }
+        self.map.swap_tiles(&mut self.next);
+        self.generation += 1;
+        changed
+    }
+
+    /// Step until a generation changes nothing, returning how many
+    /// generations that took, the unchanged one included.
+    pub fn run_until_stable<F>(&mut self, mut rule: F) -> usize
+    where
+        F: FnMut(Pos, &T, &Map<T>) -> T,
+    {
+        let start = self.generation;
+        while self.step(&mut rule) > 0 {}
+        self.generation - start
+    }
+
+    /// Like `run_until_stable`, but give up with `None` if the map still
+    /// changes after `max_generations`, for rules that may never settle.
+    pub fn run_until_stable_within<F>(
+        &mut self,
+        max_generations: usize,
+        mut rule: F,
+    ) -> Option<usize>
+    where
+        F: FnMut(Pos, &T, &Map<T>) -> T,
+    {
+        (1..=max_generations).find(|_| self.step(&mut rule) == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life(pos: Pos, tile: &char, map: &Map<char>) -> char {
+        let alive = map.neighbors8(pos).filter(|(_, &c)| c == '#').count();
+        match (tile, alive) {
+            ('#', 2 | 3) | ('.', 3) => '#',
+            _ => '.',
+        }
+    }
+
+    fn automaton(s: &str) -> Automaton<char> {
+        Automaton::new(Map::from_grid(s).unwrap())
+    }
+
+    const BLINKER: &str = ".....\n..#..\n..#..\n..#..\n.....";
+
+    #[test]
+    fn blinker() {
+        let mut life_map = automaton(BLINKER);
+        assert_eq!(life_map.step(life), 4);
+        assert_eq!(
+            life_map.map().to_string(),
+            "\n.....\n.....\n.###.\n.....\n....."
+        );
+        assert_eq!(life_map.step(life), 4);
+        assert_eq!(life_map.map().to_string(), format!("\n{}", BLINKER));
+        assert_eq!(life_map.generation(), 2);
+
+        assert_eq!(life_map.run_until_stable_within(10, life), None);
+        assert_eq!(life_map.generation(), 12);
+    }
+
+    #[test]
+    fn still_life() {
+        let mut block = automaton("....\n.##.\n.##.\n....");
+        assert_eq!(block.step(life), 0);
+        assert_eq!(block.run_until_stable(life), 1);
+        assert_eq!(block.run_until_stable_within(5, life), Some(1));
+        assert_eq!(block.generation(), 3);
+    }
+
+    #[test]
+    fn dies_out() {
+        let mut pair = automaton("....\n.##.\n....");
+        assert_eq!(pair.run_until_stable(life), 2);
+        assert_eq!(pair.map().get_tiles(), ['.'; 12]);
+
+        let mut pair = automaton("....\n.##.\n....");
+        assert_eq!(pair.run_until_stable_within(2, life), Some(2));
+        pair.map_mut().set(Pos::new(0, 0), '#');
+        assert_eq!(pair.step(life), 1);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::grid::FromChar;
+use crate::pos::{Pos, EAST, NORTH, SOUTH, WEST};
+
+/// One of the four orthogonal directions, with north pointing up (-y).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// Clockwise, starting north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+    /// Right, down, left, up.
+    pub const RDLU: [Dir4; 4] = [Dir4::East, Dir4::South, Dir4::West, Dir4::North];
+
+    /// All four directions clockwise, starting with this one.
+    pub fn clockwise(self) -> impl Iterator<Item = Dir4> {
+        (0..4).map(move |turns| Self::ALL[(self as usize + turns) % 4])
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The step to take to move one tile this way.
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir4::North => NORTH,
+            Dir4::East => EAST,
+            Dir4::South => SOUTH,
+            Dir4::West => WEST,
+        }
+    }
+
+    /// The direction `offset` is a single step towards, if any.
+    pub fn from_offset(offset: Pos) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.offset() == offset)
+    }
+}
+
+impl From<Dir4> for Pos {
+    fn from(dir: Dir4) -> Self {
+        dir.offset()
+    }
+}
+
+/// Reads `N`/`E`/`S`/`W`, `U`/`R`/`D`/`L` and arrows, either `^>v<` or
+/// `↑→↓←`.
+impl FromChar for Dir4 {
+    type Err = &'static str;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            'N' | 'U' | '^' | '↑' => Ok(Dir4::North),
+            'E' | 'R' | '>' | '→' => Ok(Dir4::East),
+            'S' | 'D' | 'v' | '↓' => Ok(Dir4::South),
+            'W' | 'L' | '<' | '←' => Ok(Dir4::West),
+            _ => Err("expected a direction"),
+        }
+    }
+}
+
+/// One of the eight orthogonal and diagonal directions.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Clockwise, starting north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// All eight directions clockwise, starting with this one.
+    pub fn clockwise(self) -> impl Iterator<Item = Dir8> {
+        (0..8).map(move |turns| Self::ALL[(self as usize + turns) % 8])
+    }
+
+    /// Turn 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turn 45° anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The step to take to move one tile this way.
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir8::North => NORTH,
+            Dir8::NorthEast => NORTH + EAST,
+            Dir8::East => EAST,
+            Dir8::SouthEast => SOUTH + EAST,
+            Dir8::South => SOUTH,
+            Dir8::SouthWest => SOUTH + WEST,
+            Dir8::West => WEST,
+            Dir8::NorthWest => NORTH + WEST,
+        }
+    }
+
+    /// The direction `offset` is a single step towards, if any.
+    pub fn from_offset(offset: Pos) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.offset() == offset)
+    }
+}
+
+impl From<Dir8> for Pos {
+    fn from(dir: Dir8) -> Self {
+        dir.offset()
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Self::ALL[dir as usize * 2]
+    }
+}
+
+/// Reads everything `Dir4` does, plus the diagonal arrows `↗↘↙↖`.
+impl FromChar for Dir8 {
+    type Err = &'static str;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        match c {
+            '↗' => Ok(Dir8::NorthEast),
+            '↘' => Ok(Dir8::SouthEast),
+            '↙' => Ok(Dir8::SouthWest),
+            '↖' => Ok(Dir8::NorthWest),
+            _ => Dir4::from_char(c).map(Dir8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dir4_turns() {
+        assert_eq!(Dir4::North.turn_right(), Dir4::East);
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir4::East.reverse(), Dir4::West);
+        assert_eq!(
+            Dir4::South.clockwise().collect::<Vec<_>>(),
+            [Dir4::South, Dir4::West, Dir4::North, Dir4::East]
+        );
+    }
+
+    #[test]
+    fn dir8_turns() {
+        assert_eq!(Dir8::North.turn_right(), Dir8::NorthEast);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::NorthWest.turn_right(), Dir8::North);
+        assert_eq!(Dir8::SouthEast.reverse(), Dir8::NorthWest);
+        assert!(Dir8::SouthWest.is_diagonal());
+        assert!(!Dir8::South.is_diagonal());
+        assert_eq!(
+            Dir8::West.clockwise().collect::<Vec<_>>(),
+            [
+                Dir8::West,
+                Dir8::NorthWest,
+                Dir8::North,
+                Dir8::NorthEast,
+                Dir8::East,
+                Dir8::SouthEast,
+                Dir8::South,
+                Dir8::SouthWest,
+            ]
+        );
+        assert_eq!(Dir8::from(Dir4::East), Dir8::East);
+        assert_eq!(Dir8::from(Dir4::West), Dir8::West);
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(Dir4::North.offset(), Pos::new(0, -1));
+        assert_eq!(Dir8::SouthWest.offset(), Pos::new(-1, 1));
+        for dir in Dir4::ALL {
+            assert_eq!(Dir4::from_offset(dir.offset()), Some(dir));
+        }
+        for dir in Dir8::ALL {
+            assert_eq!(Dir8::from_offset(dir.offset()), Some(dir));
+        }
+        assert_eq!(Dir4::from_offset(Pos::new(1, 1)), None);
+        assert_eq!(Dir8::from_offset(Pos::new(2, 0)), None);
+        assert_eq!(Dir8::from_offset(Pos::new(0, 0)), None);
+    }
+
+    #[test]
+    fn from_char() {
+        for (chars, dir) in [
+            ("NU^↑", Dir4::North),
+            ("ER>→", Dir4::East),
+            ("SDv↓", Dir4::South),
+            ("WL<←", Dir4::West),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Dir4::from_char(c), Ok(dir));
+                assert_eq!(Dir8::from_char(c), Ok(dir.into()));
+            }
+        }
+        assert_eq!(Dir8::from_char('↗'), Ok(Dir8::NorthEast));
+        assert_eq!(Dir8::from_char('↘'), Ok(Dir8::SouthEast));
+        assert_eq!(Dir8::from_char('↙'), Ok(Dir8::SouthWest));
+        assert_eq!(Dir8::from_char('↖'), Ok(Dir8::NorthWest));
+        assert!(Dir4::from_char('↗').is_err());
+        assert!(Dir4::from_char('x').is_err());
+    }
+
+    #[test]
+    fn neighbor_order() {
+        let pos = Pos::new(5, 5);
+        let steps = |neighbors: Vec<Pos>| -> Vec<_> {
+            neighbors
+                .into_iter()
+                .map(|n| Dir4::from_offset(n - pos).unwrap())
+                .collect()
+        };
+        assert_eq!(
+            steps(pos.neighbors_simple().collect()),
+            [Dir4::East, Dir4::West, Dir4::South, Dir4::North]
+        );
+        assert_eq!(
+            steps(pos.neighbors_rdlu().collect()),
+            [Dir4::East, Dir4::South, Dir4::West, Dir4::North]
+        );
+    }
+}
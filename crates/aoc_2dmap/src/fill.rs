@@ -0,0 +1,186 @@
+use crate::map::Map;
+use crate::pos::Pos;
+
+/// Which neighbours of a tile it is connected to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Orthogonal neighbours only.
+    Four,
+    /// Orthogonal and diagonal neighbours.
+    Eight,
+}
+
+impl Connectivity {
+    /// Neighbours of `pos` that are connected to it.
+    pub fn neighbors(self, pos: Pos) -> impl Iterator<Item = Pos> {
+        let diagonals = match self {
+            Self::Four => 0,
+            Self::Eight => 4,
+        };
+        pos.neighbors_simple()
+            .chain(pos.neighbors_only_diag().take(diagonals))
+    }
+}
+
+/// A connected group of passable tiles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub size: usize,
+    /// Top left and bottom right corners, both inclusive.
+    pub bounds: (Pos, Pos),
+}
+
+/// Every connected component of a map, along with the index of the
+/// component each tile belongs to (`None` for impassable tiles).
+#[derive(Clone)]
+pub struct Components {
+    pub labels: Map<Option<usize>>,
+    pub components: Vec<Component>,
+}
+
+impl<T> Map<T> {
+    /// Positions reachable from `seed` through tiles for which `passable`
+    /// holds, `seed` included. Empty if `seed` itself isn't passable.
+    pub fn flood_fill<F>(&self, seed: Pos, connectivity: Connectivity, passable: F) -> Vec<Pos>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut seen = Map::fill(self.size, false);
+        self.fill_from(seed, connectivity, &passable, &mut seen)
+    }
+
+    fn fill_from<F>(
+        &self,
+        seed: Pos,
+        connectivity: Connectivity,
+        passable: &F,
+        seen: &mut Map<bool>,
+    ) -> Vec<Pos>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut filled = Vec::new();
+        let seed = match self.resolve(seed) {
+            Some(seed) if passable(&self[seed]) && !seen[seed] => seed,
+            _ => return filled,
+        };
+
+        seen[seed] = true;
+        let mut stack = vec![seed];
+        while let Some(pos) = stack.pop() {
+            filled.push(pos);
+            for n_pos in connectivity.neighbors(pos) {
+                let Some(n_pos) = self.resolve(n_pos) else {
+                    continue;
+                };
+                if !seen[n_pos] && passable(&self[n_pos]) {
+                    seen[n_pos] = true;
+                    stack.push(n_pos);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Label the connected components formed by tiles for which `passable`
+    /// holds, in reading order of their first tile.
+    pub fn components<F>(&self, connectivity: Connectivity, passable: F) -> Components
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut seen = Map::fill(self.size, false);
+        let mut labels = Map::fill(self.size, None);
+        let mut components = Vec::new();
+
+        for pos in self.iter() {
+            let filled = self.fill_from(pos, connectivity, &passable, &mut seen);
+            if filled.is_empty() {
+                continue;
+            }
+            let mut bounds = (pos, pos);
+            for &p in &filled {
+                labels[p] = Some(components.len());
+                bounds.0 = bounds.0.component_min(p);
+                bounds.1 = bounds.1.component_max(p);
+            }
+            components.push(Component {
+                size: filled.len(),
+                bounds,
+            });
+        }
+        Components { labels, components }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Map<char> {
+        Map::from_grid(s).unwrap()
+    }
+
+    const CAVE: &str = "..#..\n..#.#\n##.#.";
+
+    fn open(c: &char) -> bool {
+        *c == '.'
+    }
+
+    #[test]
+    fn flood_fill() {
+        let map = grid(CAVE);
+        let mut filled = map.flood_fill(Pos::new(3, 0), Connectivity::Four, open);
+        filled.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(filled, [Pos::new(3, 0), Pos::new(4, 0), Pos::new(3, 1)]);
+
+        assert_eq!(
+            map.flood_fill(Pos::new(0, 0), Connectivity::Eight, open)
+                .len(),
+            9
+        );
+        assert!(map
+            .flood_fill(Pos::new(2, 0), Connectivity::Four, open)
+            .is_empty());
+        assert!(map
+            .flood_fill(Pos::new(-1, 0), Connectivity::Four, open)
+            .is_empty());
+    }
+
+    #[test]
+    fn components() {
+        let Components { labels, components } = grid(CAVE).components(Connectivity::Four, open);
+        let component = |size, min: (i32, i32), max: (i32, i32)| Component {
+            size,
+            bounds: (min.into(), max.into()),
+        };
+        assert_eq!(
+            components,
+            [
+                component(4, (0, 0), (1, 1)),
+                component(3, (3, 0), (4, 1)),
+                component(1, (2, 2), (2, 2)),
+                component(1, (4, 2), (4, 2)),
+            ]
+        );
+        let labels = labels.render(|label| match label {
+            Some(idx) => char::from_digit(*idx as u32, 10).unwrap(),
+            None => '#',
+        });
+        assert_eq!(labels.to_string(), "00#11\n00#1#\n##2#3");
+
+        let all = grid(CAVE).components(Connectivity::Eight, open).components;
+        assert_eq!(all, [component(9, (0, 0), (4, 2))]);
+    }
+
+    #[test]
+    fn passable_boundary() {
+        let map = grid("45\n54");
+        let below = map.components(Connectivity::Four, |&d| d < '5').components;
+        assert_eq!(below.len(), 2);
+        assert!(below.iter().all(|c| c.size == 1));
+
+        let up_to = map.components(Connectivity::Four, |&d| d <= '5').components;
+        assert_eq!(up_to.len(), 1);
+        assert_eq!(up_to[0].size, 4);
+    }
+}
@@ -0,0 +1,153 @@
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::map::Map;
+
+/// Conversion of a single character of a text grid into a map tile.
+pub trait FromChar: Sized {
+    type Err: Display;
+
+    fn from_char(c: char) -> Result<Self, Self::Err>;
+}
+
+impl FromChar for char {
+    type Err = Infallible;
+
+    fn from_char(c: char) -> Result<Self, Self::Err> {
+        Ok(c)
+    }
+}
+
+macro_rules! from_digit {
+    ($($t:ty),+) => {
+        $(
+            impl FromChar for $t {
+                type Err = &'static str;
+
+                fn from_char(c: char) -> Result<Self, Self::Err> {
+                    c.to_digit(10).map(|d| d as $t).ok_or("expected a digit")
+                }
+            }
+        )+
+    };
+}
+
+from_digit!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+
+/// Why and where (1-based row and column of the text) a grid failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridError {
+    pub row: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {}, column {}: {}",
+            self.row, self.column, self.message
+        )
+    }
+}
+
+impl Error for GridError {}
+
+impl<T: FromChar> Map<T> {
+    /// Parse a grid with one character per tile and one line per row, all
+    /// rows as wide as the first one.
+    pub fn from_grid(s: &str) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut tiles = Vec::with_capacity(s.len());
+
+        for (y, line) in s.lines().enumerate() {
+            let error = |x: usize, message: String| GridError {
+                row: y + 1,
+                column: x + 1,
+                message,
+            };
+            let mut x = 0;
+            for c in line.chars() {
+                if width.is_some_and(|width| x == width) {
+                    return Err(error(x, format!("row is longer than {} tiles", x)));
+                }
+                let tile = T::from_char(c)
+                    .map_err(|err| error(x, format!("invalid tile '{}': {}", c, err)))?;
+                tiles.push(tile);
+                x += 1;
+            }
+            match width {
+                Some(width) if x < width => {
+                    return Err(error(x, format!("row is shorter than {} tiles", width)))
+                }
+                Some(_) => {}
+                None => width = Some(x),
+            }
+            height += 1;
+        }
+        Ok(Map::new((width.unwrap_or(0), height), tiles.into_iter()))
+    }
+}
+
+impl<T: FromChar> FromStr for Map<T> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_grid(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pos::Pos;
+
+    fn error(row: usize, column: usize, message: &str) -> GridError {
+        GridError {
+            row,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn digits() {
+        let map: Map<u8> = "12\n34\n".parse().unwrap();
+        assert_eq!(map.size, Pos::new(2, 2));
+        assert_eq!(map.get_tiles(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn ragged_rows() {
+        let longer = Map::<char>::from_grid("ab\nab\nabc").err();
+        assert_eq!(longer, Some(error(3, 3, "row is longer than 2 tiles")));
+        let shorter = Map::<char>::from_grid("abc\nab\nabc").err();
+        assert_eq!(shorter, Some(error(2, 3, "row is shorter than 3 tiles")));
+        let blank = Map::<char>::from_grid("abc\n\nabc").err();
+        assert_eq!(blank, Some(error(2, 1, "row is shorter than 3 tiles")));
+    }
+
+    #[test]
+    fn invalid_tile() {
+        let err = Map::<u8>::from_grid("12\n3x").err().unwrap();
+        assert_eq!(err, error(2, 2, "invalid tile 'x': expected a digit"));
+        assert_eq!(
+            err.to_string(),
+            "row 2, column 2: invalid tile 'x': expected a digit"
+        );
+        // columns count characters, not bytes
+        let err = Map::<u8>::from_grid("00\n0é").err().unwrap();
+        assert_eq!((err.row, err.column), (2, 2));
+    }
+
+    #[test]
+    fn empty() {
+        let map = Map::<char>::from_grid("").unwrap();
+        assert_eq!(map.size, Pos::new(0, 0));
+        assert!(map.get_tiles().is_empty());
+    }
+}
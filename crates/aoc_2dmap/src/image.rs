@@ -0,0 +1,277 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::map::{Map, MapSize};
+
+/// Red, green and blue channels of a pixel.
+pub type Rgb = [u8; 3];
+
+/// File formats an `Image` can be written as.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// Binary portable pixmap (`P6`).
+    Ppm,
+    /// Needs the `png` feature.
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// Pick the format from the extension of `path`.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let ext = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "ppm" => Some(Self::Ppm),
+            "png" => Some(Self::Png),
+            "svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            Self::Png => "png",
+            Self::Svg => "svg",
+        }
+    }
+}
+
+/// A map turned into pixels, built with `Map::to_image`. Every tile is drawn
+/// as a square of `scale` pixels a side.
+#[derive(Clone)]
+pub struct Image {
+    size: MapSize,
+    scale: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl<T> Map<T> {
+    /// Colour every tile with `colour`.
+    pub fn to_image<F: Fn(&T) -> Rgb>(&self, colour: F) -> Image {
+        Image {
+            size: self.size,
+            scale: 1,
+            pixels: self.get_tiles().iter().map(colour).collect(),
+        }
+    }
+}
+
+impl Image {
+    /// Draw every tile as a square of `scale` pixels a side.
+    pub fn scale(mut self, scale: usize) -> Self {
+        assert!(scale > 0, "image scale must be positive");
+        self.scale = scale;
+        self
+    }
+
+    /// Width and height in pixels.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (
+            self.size.x as usize * self.scale,
+            self.size.y as usize * self.scale,
+        )
+    }
+
+    /// Write the image to `path`, in the format its extension names.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let path = path.as_ref();
+        let format = ImageFormat::from_path(path).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown image format for {}", path.display()),
+            )
+        })?;
+        let mut buf = Vec::new();
+        self.write(&mut buf, format)?;
+        fs::write(path, buf)
+    }
+
+    pub fn write<W: Write>(&self, out: W, format: ImageFormat) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(out),
+            ImageFormat::Png => self.write_png(out),
+            ImageFormat::Svg => self.write_svg(out),
+        }
+    }
+
+    /// RGB bytes of every pixel, row by row.
+    fn raw(&self) -> Vec<u8> {
+        let (width, height) = self.dimensions();
+        let mut raw = Vec::with_capacity(width * height * 3);
+        for row in self.pixels.chunks(self.size.x.max(1) as usize) {
+            let start = raw.len();
+            for pixel in row {
+                for _ in 0..self.scale {
+                    raw.extend_from_slice(pixel);
+                }
+            }
+            for _ in 1..self.scale {
+                raw.extend_from_within(start..start + width * 3);
+            }
+        }
+        raw
+    }
+
+    fn write_ppm<W: Write>(&self, mut out: W) -> io::Result<()> {
+        let (width, height) = self.dimensions();
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+        out.write_all(&self.raw())
+    }
+
+    #[cfg(feature = "png")]
+    fn write_png<W: Write>(&self, out: W) -> io::Result<()> {
+        let (width, height) = self.dimensions();
+        let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.raw()))
+            .map_err(io::Error::other)
+    }
+
+    #[cfg(not(feature = "png"))]
+    fn write_png<W: Write>(&self, _out: W) -> io::Result<()> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "aoc_2dmap was built without the png feature",
+        ))
+    }
+
+    /// One rectangle for every run of same coloured tiles in a row.
+    fn write_svg<W: Write>(&self, mut out: W) -> io::Result<()> {
+        let (width, height) = self.dimensions();
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {2} {3}" shape-rendering="crispEdges">"#,
+            width, height, self.size.x, self.size.y
+        )?;
+        for (y, row) in self.pixels.chunks(self.size.x.max(1) as usize).enumerate() {
+            let mut x = 0;
+            for run in row.chunk_by(|a, b| a == b) {
+                let [r, g, b] = run[0];
+                writeln!(
+                    out,
+                    r##"<rect x="{}" y="{}" width="{}" height="1" fill="#{:02x}{:02x}{:02x}"/>"##,
+                    x,
+                    y,
+                    run.len(),
+                    r,
+                    g,
+                    b
+                )?;
+                x += run.len();
+            }
+        }
+        writeln!(out, "</svg>")
+    }
+}
+
+/// Numbered images written to a directory, one per `push`, to be stitched
+/// into an animation.
+pub struct Frames {
+    dir: PathBuf,
+    format: ImageFormat,
+    count: usize,
+}
+
+impl Frames {
+    /// Write frames as `frame_00000.<ext>`, `frame_00001.<ext>`, ... into
+    /// `dir`, creating it if needed.
+    pub fn new<P: Into<PathBuf>>(dir: P, format: ImageFormat) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir,
+            format,
+            count: 0,
+        })
+    }
+
+    /// Write `image` as the next frame, returning its path.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.count,
+            self.format.extension()
+        ));
+        image.save(&path)?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// Number of frames written so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colour(c: &char) -> Rgb {
+        match c {
+            'a' => [1, 2, 3],
+            'b' => [4, 5, 6],
+            'c' => [7, 8, 9],
+            _ => [0xff, 0x80, 0x00],
+        }
+    }
+
+    fn write(image: &Image, format: ImageFormat) -> Vec<u8> {
+        let mut buf = Vec::new();
+        image.write(&mut buf, format).unwrap();
+        buf
+    }
+
+    #[test]
+    fn ppm() {
+        let map: Map<char> = Map::from_grid("ab\ncd").unwrap();
+        let image = map.to_image(colour).scale(2);
+        assert_eq!(image.dimensions(), (4, 4));
+
+        let mut expected = b"P6\n4 4\n255\n".to_vec();
+        for row in [[1, 2, 3, 1, 2, 3, 4, 5, 6, 4, 5, 6]; 2] {
+            expected.extend(row);
+        }
+        for row in [[7, 8, 9, 7, 8, 9, 255, 128, 0, 255, 128, 0]; 2] {
+            expected.extend(row);
+        }
+        assert_eq!(write(&image, ImageFormat::Ppm), expected);
+    }
+
+    #[test]
+    fn svg_runs() {
+        let map: Map<char> = Map::from_grid("aab\nddd").unwrap();
+        let svg = write(&map.to_image(colour).scale(2), ImageFormat::Svg);
+        assert_eq!(
+            String::from_utf8(svg).unwrap(),
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" width="6" height="4" viewBox="0 0 3 2" shape-rendering="crispEdges">"#,
+                "\n",
+                r##"<rect x="0" y="0" width="2" height="1" fill="#010203"/>"##,
+                "\n",
+                r##"<rect x="2" y="0" width="1" height="1" fill="#040506"/>"##,
+                "\n",
+                r##"<rect x="0" y="1" width="3" height="1" fill="#ff8000"/>"##,
+                "\n",
+                "</svg>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn format_from_path() {
+        assert_eq!(ImageFormat::from_path("out/a.PPM"), Some(ImageFormat::Ppm));
+        assert_eq!(ImageFormat::from_path("a.svg"), Some(ImageFormat::Svg));
+        assert_eq!(ImageFormat::from_path("a.gif"), None);
+        assert_eq!(ImageFormat::from_path("a"), None);
+    }
+}
@@ -1,3 +1,14 @@
+mod automaton;
+mod dir;
+mod fill;
+mod grid;
+mod image;
 mod map;
 mod pos;
+mod posn;
 pub mod prelude;
+mod render;
+mod segment;
+mod sparse;
+mod transform;
+mod view;
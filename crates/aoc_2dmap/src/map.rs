@@ -4,10 +4,40 @@ use std::ops::{Index, IndexMut};
 
 pub type MapSize = Pos;
 
+/// Which edges of a map wrap around to the opposite one.
+#[derive(Default, Debug, Copy, Clone, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    WrapX,
+    WrapY,
+    Torus,
+}
+
+impl Topology {
+    pub fn wraps_x(self) -> bool {
+        matches!(self, Self::WrapX | Self::Torus)
+    }
+
+    pub fn wraps_y(self) -> bool {
+        matches!(self, Self::WrapY | Self::Torus)
+    }
+
+    /// The same topology with the x and y axes swapped.
+    pub fn transposed(self) -> Self {
+        match self {
+            Self::WrapX => Self::WrapY,
+            Self::WrapY => Self::WrapX,
+            other => other,
+        }
+    }
+}
+
 #[derive(Hash, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Map<T> {
     pub size: MapSize,
     tiles: Vec<T>,
+    topology: Topology,
 }
 
 impl<T: Clone> Clone for Map<T> {
@@ -15,6 +45,7 @@ impl<T: Clone> Clone for Map<T> {
         Self {
             size: self.size,
             tiles: self.tiles.to_owned(),
+            topology: self.topology,
         }
     }
 }
@@ -24,7 +55,11 @@ impl<T> Map<T> {
         let size = size.into();
         let tiles = tiles.collect::<Vec<T>>();
         assert_eq!(tiles.len(), (size.x * size.y) as usize);
-        Self { size, tiles }
+        Self {
+            size,
+            tiles,
+            topology: Topology::Bounded,
+        }
     }
 
     pub fn fill<S: Into<MapSize>>(size: S, default: T) -> Self
@@ -33,7 +68,11 @@ impl<T> Map<T> {
     {
         let size = size.into();
         let tiles = vec![default; size.x as usize * size.y as usize];
-        Self { size, tiles }
+        Self {
+            size,
+            tiles,
+            topology: Topology::Bounded,
+        }
     }
 
     pub fn fill_default<S: Into<MapSize>>(size: S) -> Self
@@ -46,7 +85,19 @@ impl<T> Map<T> {
         for _ in 0..num_tiles {
             tiles.push(T::default());
         }
-        Self { size, tiles }
+        Self {
+            size,
+            tiles,
+            topology: Topology::Bounded,
+        }
+    }
+
+    pub fn with_topology(self, topology: Topology) -> Self {
+        Self { topology, ..self }
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
     }
 
     pub fn get<P: AsRef<Pos>>(&self, pos: P) -> Option<T>
@@ -65,17 +116,17 @@ impl<T> Map<T> {
     where
         T: Clone,
     {
-        let pos = pos.as_ref();
+        let pos = self.wrap(*pos.as_ref());
         self.tiles[(pos.x + pos.y * self.size.x) as usize].clone()
     }
 
     pub fn get_unchecked_ref<P: AsRef<Pos>>(&self, pos: P) -> &T {
-        let pos = pos.as_ref();
+        let pos = self.wrap(*pos.as_ref());
         &self.tiles[(pos.x + pos.y * self.size.x) as usize]
     }
 
     pub fn get_unchecked_mut_ref<P: AsRef<Pos>>(&mut self, pos: P) -> &mut T {
-        let pos = pos.as_ref();
+        let pos = self.wrap(*pos.as_ref());
         &mut self.tiles[(pos.x + pos.y * self.size.x) as usize]
     }
 
@@ -93,10 +144,19 @@ impl<T> Map<T> {
         (0..self.size.y).map(move |y| self.get_unchecked(Pos::new(col, y)))
     }
 
-    pub fn get_tiles(&self) -> &Vec<T> {
+    /// Every tile, row after row.
+    pub fn get_tiles(&self) -> &[T] {
         &self.tiles
     }
 
+    pub fn get_tiles_mut(&mut self) -> &mut [T] {
+        &mut self.tiles
+    }
+
+    pub fn into_tiles(self) -> Vec<T> {
+        self.tiles
+    }
+
     pub fn set<P: AsRef<Pos>>(&mut self, pos: P, tile: T) {
         if let Some(index) = self.index(*pos.as_ref()) {
             self.tiles[index] = tile;
@@ -111,13 +171,17 @@ impl<T> Map<T> {
         }
     }
 
-    #[allow(clippy::ptr_arg)]
-    pub fn swap_vec(&mut self, new_tiles: &Vec<T>)
+    pub fn swap_vec(&mut self, new_tiles: &[T])
     where
         T: Copy,
     {
-        assert_eq!(new_tiles.len(), (self.size.x * self.size.y) as usize);
-        self.tiles.clone_from(new_tiles);
+        self.tiles.copy_from_slice(new_tiles);
+    }
+
+    /// Exchange the tiles with `tiles`, which must hold as many of them.
+    pub(crate) fn swap_tiles(&mut self, tiles: &mut Vec<T>) {
+        assert_eq!(tiles.len(), self.tiles.len());
+        std::mem::swap(&mut self.tiles, tiles);
     }
 
     pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
@@ -128,7 +192,82 @@ impl<T> Map<T> {
         (0..self.size.x).contains(&pos.x) && (0..self.size.y).contains(&pos.y)
     }
 
+    /// The part of the rectangle of `size` starting at `from` that lies on
+    /// the map, as its corner and size.
+    pub(crate) fn clamp_rect(&self, from: Pos, size: MapSize) -> (Pos, MapSize) {
+        let clamp =
+            |p: Pos, min: Pos, max: Pos| Pos::new(p.x.clamp(min.x, max.x), p.y.clamp(min.y, max.y));
+        let from = clamp(from, Pos::default(), self.size);
+        let to = clamp(from + size, from, self.size);
+        (from, to - from)
+    }
+
+    /// Bring `pos` back onto the map along the axes that wrap around. It may
+    /// still be off the map along the others, or along a wrapping axis the
+    /// map has no tiles on.
+    pub fn wrap(&self, pos: Pos) -> Pos {
+        Pos {
+            x: if self.topology.wraps_x() && self.size.x > 0 {
+                pos.x.rem_euclid(self.size.x)
+            } else {
+                pos.x
+            },
+            y: if self.topology.wraps_y() && self.size.y > 0 {
+                pos.y.rem_euclid(self.size.y)
+            } else {
+                pos.y
+            },
+        }
+    }
+
+    /// The tile `pos` refers to under the map's topology, if any. Handy for
+    /// neighbours: `pos.neighbors_diag().filter_map(|n| map.resolve(n))`.
+    pub fn resolve(&self, pos: Pos) -> Option<Pos> {
+        Some(self.wrap(pos)).filter(|&pos| self.within(pos))
+    }
+
+    /// The up to four orthogonal neighbours of `pos` that are on the map.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.on_map(pos.neighbors_simple())
+    }
+
+    /// The up to eight orthogonal and diagonal neighbours of `pos` that are on the map.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.on_map(pos.neighbors_diag())
+    }
+
+    /// Call `f` on each of the orthogonal neighbours of `pos` that are on the
+    /// map. A closure rather than an iterator, since on a wrapping map two
+    /// neighbours may be the same tile.
+    pub fn neighbors4_mut<F: FnMut(Pos, &mut T)>(&mut self, pos: Pos, f: F) {
+        self.on_map_mut(pos.neighbors_simple(), f)
+    }
+
+    /// Like `neighbors4_mut`, diagonals included.
+    pub fn neighbors8_mut<F: FnMut(Pos, &mut T)>(&mut self, pos: Pos, f: F) {
+        self.on_map_mut(pos.neighbors_diag(), f)
+    }
+
+    fn on_map(&self, positions: impl Iterator<Item = Pos>) -> impl Iterator<Item = (Pos, &T)> {
+        positions
+            .filter_map(|pos| self.resolve(pos))
+            .map(|pos| (pos, self.get_unchecked_ref(pos)))
+    }
+
+    fn on_map_mut<F: FnMut(Pos, &mut T)>(
+        &mut self,
+        positions: impl Iterator<Item = Pos>,
+        mut f: F,
+    ) {
+        for pos in positions {
+            if let Some(pos) = self.resolve(pos) {
+                f(pos, self.get_unchecked_mut_ref(pos));
+            }
+        }
+    }
+
     fn index(&self, pos: Pos) -> Option<usize> {
+        let pos = self.wrap(pos);
         if self.within(pos) {
             Some((pos.x + pos.y * self.size.x) as _)
         } else {
@@ -164,3 +303,130 @@ impl<T: Display> Display for Map<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Map<char> {
+        Map::from_grid(s).unwrap()
+    }
+
+    fn tiles<'a>(neighbors: impl Iterator<Item = (Pos, &'a char)>) -> String {
+        neighbors.map(|(_, &c)| c).collect()
+    }
+
+    #[test]
+    fn bounded() {
+        let map = grid("ab\ncd");
+        assert_eq!(map.get(Pos::new(1, 1)), Some('d'));
+        assert_eq!(map.get(Pos::new(-1, 0)), None);
+        assert_eq!(map.get(Pos::new(2, 0)), None);
+        assert_eq!(map.get(Pos::new(0, 2)), None);
+        assert_eq!(map.resolve(Pos::new(0, -1)), None);
+        assert_eq!(tiles(map.neighbors4(Pos::new(0, 0))), "bc");
+    }
+
+    #[test]
+    fn neighbor_order() {
+        let map = grid("abc\ndef\nghi");
+        let center = Pos::new(1, 1);
+        assert_eq!(tiles(map.neighbors4(center)), "fdhb");
+        assert_eq!(tiles(map.neighbors8(center)), "fdhbicga");
+        let positions: Vec<_> = map.neighbors4(center).map(|(pos, _)| pos).collect();
+        assert_eq!(
+            positions,
+            [
+                Pos::new(2, 1),
+                Pos::new(0, 1),
+                Pos::new(1, 2),
+                Pos::new(1, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors_clipped() {
+        let map = grid("abc\ndef\nghi");
+        assert_eq!(tiles(map.neighbors4(Pos::new(0, 0))), "bd");
+        assert_eq!(tiles(map.neighbors8(Pos::new(0, 0))), "bde");
+        assert_eq!(tiles(map.neighbors4(Pos::new(2, 2))), "hf");
+        assert_eq!(tiles(map.neighbors8(Pos::new(2, 2))), "hfe");
+        assert_eq!(tiles(map.neighbors4(Pos::new(1, 0))), "cae");
+        assert_eq!(tiles(map.neighbors8(Pos::new(1, 0))), "caefd");
+        assert_eq!(tiles(map.neighbors8(Pos::new(3, 1))), "fic");
+        assert_eq!(map.neighbors8(Pos::new(5, 5)).count(), 0);
+    }
+
+    #[test]
+    fn neighbors_mut() {
+        let mut map = grid("abc\ndef\nghi");
+        let mut visited = Vec::new();
+        map.neighbors4_mut(Pos::new(1, 1), |pos, tile| {
+            visited.push(pos);
+            *tile = tile.to_ascii_uppercase();
+        });
+        assert_eq!(
+            visited,
+            [
+                Pos::new(2, 1),
+                Pos::new(0, 1),
+                Pos::new(1, 2),
+                Pos::new(1, 0)
+            ]
+        );
+        assert_eq!(map.to_string(), "\naBc\nDeF\ngHi");
+
+        let mut visited = String::new();
+        map.neighbors8_mut(Pos::new(0, 2), |_, tile| {
+            visited.push(*tile);
+            *tile = '#';
+        });
+        assert_eq!(visited, "HDe");
+        assert_eq!(map.to_string(), "\naBc\n##F\ng#i");
+    }
+
+    #[test]
+    fn wrap_x() {
+        let map = grid("abc\ndef\nghi").with_topology(Topology::WrapX);
+        assert_eq!(map.get(Pos::new(-1, 0)), Some('c'));
+        assert_eq!(map.get(Pos::new(3, 1)), Some('d'));
+        assert_eq!(map.get(Pos::new(-7, 2)), Some('i'));
+        assert_eq!(map.get(Pos::new(0, -1)), None);
+        assert_eq!(map.get(Pos::new(0, 3)), None);
+        assert_eq!(map.resolve(Pos::new(4, 2)), Some(Pos::new(1, 2)));
+        assert_eq!(tiles(map.neighbors4(Pos::new(0, 0))), "bcd");
+        assert_eq!(tiles(map.neighbors8(Pos::new(0, 0))), "bcdef");
+    }
+
+    #[test]
+    fn torus() {
+        let map = grid("abc\ndef\nghi").with_topology(Topology::Torus);
+        assert_eq!(map.get(Pos::new(-1, -1)), Some('i'));
+        assert_eq!(map.get(Pos::new(5, 4)), Some('f'));
+        assert_eq!(map[Pos::new(3, 3)], 'a');
+        assert_eq!(tiles(map.neighbors4(Pos::new(0, 0))), "bcdg");
+        assert_eq!(tiles(map.neighbors8(Pos::new(0, 0))), "bcdgehfi");
+    }
+
+    #[test]
+    fn torus_neighbors_mut_can_repeat() {
+        let mut map = Map::fill((2, 1), 0).with_topology(Topology::Torus);
+        map.neighbors4_mut(Pos::new(0, 0), |_, count| *count += 1);
+        assert_eq!(map.get_tiles(), [2, 2]);
+    }
+
+    #[test]
+    fn empty_wrapping_maps() {
+        let map: Map<char> = grid("").with_topology(Topology::Torus);
+        assert_eq!(map.size, Pos::new(0, 0));
+        assert_eq!(map.get(Pos::new(0, 0)), None);
+        assert_eq!(map.resolve(Pos::new(-1, 3)), None);
+        assert_eq!(map.neighbors8(Pos::new(0, 0)).count(), 0);
+
+        let map: Map<char> = grid("\n\n").with_topology(Topology::WrapX);
+        assert_eq!(map.size, Pos::new(0, 2));
+        assert_eq!(map.get(Pos::new(0, 1)), None);
+        assert_eq!(map.neighbors4(Pos::new(0, 1)).count(), 0);
+    }
+}
@@ -1,8 +1,10 @@
 use std::iter::once;
-use std::ops::{Add, AddAssign, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use num_traits::PrimInt;
 
+use crate::dir::Dir4;
+
 #[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Pos {
     pub x: i32,
@@ -14,16 +16,93 @@ impl Pos {
         Self { x, y }
     }
 
+    /// Panics if a coordinate doesn't fit an `i32`; see `checked_new`.
     pub fn new<X: PrimInt, Y: PrimInt>(x: X, y: Y) -> Self {
         Self::from((x, y))
     }
 
+    /// `None` if a coordinate doesn't fit an `i32`.
+    pub fn checked_new<X: PrimInt, Y: PrimInt>(x: X, y: Y) -> Option<Self> {
+        Some(Pos {
+            x: x.to_i32()?,
+            y: y.to_i32()?,
+        })
+    }
+
     pub fn signum(self) -> Self {
         Pos {
             x: self.x.signum(),
             y: self.y.signum(),
         }
     }
+
+    pub fn abs(self) -> Self {
+        Pos {
+            x: self.x.abs(),
+            y: self.y.abs(),
+        }
+    }
+
+    pub fn dot(self, other: Self) -> i32 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Sum of the distances along both axes.
+    pub fn manhattan(self, other: Self) -> i32 {
+        let d = (self - other).abs();
+        d.x + d.y
+    }
+
+    /// Largest of the distances along both axes.
+    pub fn chebyshev(self, other: Self) -> i32 {
+        let d = (self - other).abs();
+        d.x.max(d.y)
+    }
+
+    /// The smallest coordinate on both axes.
+    pub fn component_min(self, other: Self) -> Self {
+        Pos {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    /// The largest coordinate on both axes.
+    pub fn component_max(self, other: Self) -> Self {
+        Pos {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Pos {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Pos {
+            x: self.x.checked_sub(rhs.x)?,
+            y: self.y.checked_sub(rhs.y)?,
+        })
+    }
+
+    pub fn checked_mul(self, rhs: i32) -> Option<Self> {
+        Some(Pos {
+            x: self.x.checked_mul(rhs)?,
+            y: self.y.checked_mul(rhs)?,
+        })
+    }
+
+    /// `None` when dividing by zero or on overflow.
+    pub fn checked_div(self, rhs: i32) -> Option<Self> {
+        Some(Pos {
+            x: self.x.checked_div(rhs)?,
+            y: self.y.checked_div(rhs)?,
+        })
+    }
 }
 
 impl AsRef<Pos> for Pos {
@@ -38,10 +117,7 @@ where
     Y: PrimInt,
 {
     fn from(tpl: (X, Y)) -> Self {
-        Pos {
-            x: tpl.0.to_i32().unwrap(),
-            y: tpl.1.to_i32().unwrap(),
-        }
+        Pos::checked_new(tpl.0, tpl.1).expect("coordinates out of range for a Pos")
     }
 }
 
@@ -73,29 +149,76 @@ impl Sub for Pos {
     }
 }
 
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Pos {
+    type Output = Pos;
+
+    fn neg(self) -> Self::Output {
+        Pos {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Mul<i32> for Pos {
+    type Output = Pos;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Pos {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl MulAssign<i32> for Pos {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+/// Divides both coordinates, rounding towards zero.
+impl Div<i32> for Pos {
+    type Output = Pos;
+
+    fn div(self, rhs: i32) -> Self::Output {
+        Pos {
+            x: self.x / rhs,
+            y: self.y / rhs,
+        }
+    }
+}
+
+impl DivAssign<i32> for Pos {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
+
 impl Pos {
     pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
         (0..self.y).flat_map(move |y| (0..self.x).map(move |x| Pos { x, y }))
     }
 
+    /// The positions one step away towards each of `dirs`, in that order.
+    pub fn neighbors_in<I: IntoIterator<Item = Dir4>>(self, dirs: I) -> impl Iterator<Item = Pos> {
+        dirs.into_iter().map(move |dir| self + dir.offset())
+    }
+
+    /// Orthogonal neighbours: east, west, south, north.
     pub fn neighbors_simple(self) -> impl Iterator<Item = Pos> {
-        [
-            Pos::new(self.x + 1, self.y),
-            Pos::new(self.x - 1, self.y),
-            Pos::new(self.x, self.y + 1),
-            Pos::new(self.x, self.y - 1),
-        ]
-        .into_iter()
+        self.neighbors_in([Dir4::East, Dir4::West, Dir4::South, Dir4::North])
     }
 
+    /// Orthogonal neighbours, clockwise from the east.
     pub fn neighbors_rdlu(self) -> impl Iterator<Item = Pos> {
-        [
-            Pos::new(self.x + 1, self.y),
-            Pos::new(self.x, self.y + 1),
-            Pos::new(self.x - 1, self.y),
-            Pos::new(self.x, self.y - 1),
-        ]
-        .into_iter()
+        self.neighbors_in(Dir4::RDLU)
     }
 
     pub fn neighbors_simple_inclusive(self) -> impl Iterator<Item = Pos> {
@@ -120,6 +243,14 @@ impl Pos {
         .into_iter()
     }
 
+    /// The square of positions `radius` away from this one at most, in
+    /// reading order: a 3x3 square for a radius of 1.
+    pub fn window(self, radius: i32) -> impl Iterator<Item = Pos> {
+        (-radius..=radius).flat_map(move |dy| {
+            (-radius..=radius).map(move |dx| Pos::new(self.x + dx, self.y + dy))
+        })
+    }
+
     pub fn clockwise(self) -> Self {
         Self::new(-self.y, self.x)
     }
@@ -153,3 +284,59 @@ impl Axis {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Pos::new(3, -4);
+        let b = Pos::new(-1, 2);
+        assert_eq!(a + b, Pos::new(2, -2));
+        assert_eq!(a - b, Pos::new(4, -6));
+        assert_eq!(-a, Pos::new(-3, 4));
+        assert_eq!(a * -2, Pos::new(-6, 8));
+        assert_eq!(Pos::new(7, -7) / 2, Pos::new(3, -3));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Pos::new(2, -2));
+        c -= b;
+        assert_eq!(c, a);
+        c *= 3;
+        assert_eq!(c, Pos::new(9, -12));
+        c /= 4;
+        assert_eq!(c, Pos::new(2, -3));
+    }
+
+    #[test]
+    fn checked() {
+        let max = Pos::new(i32::MAX, 0);
+        assert_eq!(max.checked_add(Pos::new(0, 1)), Some(Pos::new(i32::MAX, 1)));
+        assert_eq!(max.checked_add(Pos::new(1, 0)), None);
+        assert_eq!(Pos::new(0, i32::MIN).checked_sub(Pos::new(0, 1)), None);
+        assert_eq!(
+            Pos::new(0, i32::MIN).checked_sub(Pos::new(1, -1)),
+            Some(Pos::new(-1, i32::MIN + 1))
+        );
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Pos::new(2, -3).checked_mul(-2), Some(Pos::new(-4, 6)));
+        assert_eq!(Pos::new(4, 6).checked_div(2), Some(Pos::new(2, 3)));
+        assert_eq!(Pos::new(4, 6).checked_div(0), None);
+        assert_eq!(Pos::new(i32::MIN, 0).checked_div(-1), None);
+    }
+
+    #[test]
+    fn checked_new() {
+        assert_eq!(Pos::checked_new(-1i64, 2u8), Some(Pos::new(-1, 2)));
+        assert_eq!(Pos::checked_new(u32::MAX, 0), None);
+        assert_eq!(Pos::checked_new(0, i64::MIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "coordinates out of range for a Pos")]
+    fn new_out_of_range() {
+        Pos::new(0, u64::MAX);
+    }
+}
@@ -0,0 +1,247 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+use num_traits::{PrimInt, Signed};
+
+use crate::pos::Pos;
+
+/// Position with `D` coordinates of type `T`, for when two aren't enough.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PosN<const D: usize, T = i32>(pub [T; D]);
+
+pub type Pos3<T = i32> = PosN<3, T>;
+
+impl<const D: usize, T: PrimInt> PosN<D, T> {
+    pub fn new(coords: [T; D]) -> Self {
+        Self(coords)
+    }
+
+    pub fn zero() -> Self {
+        Self([T::zero(); D])
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Apply `f` to every coordinate.
+    pub fn map<F: FnMut(T) -> T>(self, f: F) -> Self {
+        Self(self.0.map(f))
+    }
+
+    /// Combine the coordinates of both positions, axis by axis.
+    pub fn zip_with<F: FnMut(T, T) -> T>(self, other: Self, mut f: F) -> Self {
+        let mut coords = self.0;
+        for (c, o) in coords.iter_mut().zip(other.0) {
+            *c = f(*c, o);
+        }
+        Self(coords)
+    }
+
+    /// The smallest coordinate on every axis.
+    pub fn component_min(self, other: Self) -> Self {
+        self.zip_with(other, T::min)
+    }
+
+    /// The largest coordinate on every axis.
+    pub fn component_max(self, other: Self) -> Self {
+        self.zip_with(other, T::max)
+    }
+
+    /// Sum of the distances along every axis.
+    pub fn manhattan(self, other: Self) -> T {
+        self.axis_distances(other).fold(T::zero(), |acc, d| acc + d)
+    }
+
+    /// Largest of the distances along every axis.
+    pub fn chebyshev(self, other: Self) -> T {
+        self.axis_distances(other).fold(T::zero(), T::max)
+    }
+
+    fn axis_distances(self, other: Self) -> impl Iterator<Item = T> {
+        self.0
+            .into_iter()
+            .zip(other.0)
+            .map(|(a, b)| if a > b { a - b } else { b - a })
+    }
+
+    /// The positions one step away along a single axis.
+    pub fn neighbors_simple(self) -> impl Iterator<Item = Self> {
+        (0..D).flat_map(move |axis| {
+            let mut less = self;
+            let mut more = self;
+            less[axis] = less[axis] - T::one();
+            more[axis] = more[axis] + T::one();
+            [more, less]
+        })
+    }
+
+    /// Every position at most one step away along each axis, this one
+    /// excluded: 8 of them in 2D, 26 in 3D.
+    pub fn neighbors_diag(self) -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(D as u32);
+        (0..count)
+            .filter(move |&n| n != count / 2)
+            .map(move |mut n| {
+                let mut pos = self;
+                for c in pos.0.iter_mut() {
+                    match n % 3 {
+                        0 => *c = *c - T::one(),
+                        2 => *c = *c + T::one(),
+                        _ => (),
+                    }
+                    n /= 3;
+                }
+                pos
+            })
+    }
+}
+
+impl<T: Copy> Pos3<T> {
+    pub fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> T {
+        self.0[2]
+    }
+}
+
+impl<const D: usize, T: PrimInt> Default for PosN<D, T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const D: usize, T> From<[T; D]> for PosN<D, T> {
+    fn from(coords: [T; D]) -> Self {
+        Self(coords)
+    }
+}
+
+impl From<Pos> for PosN<2> {
+    fn from(pos: Pos) -> Self {
+        Self([pos.x, pos.y])
+    }
+}
+
+impl From<PosN<2>> for Pos {
+    fn from(PosN([x, y]): PosN<2>) -> Self {
+        Pos { x, y }
+    }
+}
+
+impl<const D: usize, T> Index<usize> for PosN<D, T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.0[axis]
+    }
+}
+
+impl<const D: usize, T> IndexMut<usize> for PosN<D, T> {
+    fn index_mut(&mut self, axis: usize) -> &mut Self::Output {
+        &mut self.0[axis]
+    }
+}
+
+impl<const D: usize, T: PrimInt> Add for PosN<D, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<const D: usize, T: PrimInt> AddAssign for PosN<D, T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const D: usize, T: PrimInt> Sub for PosN<D, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<const D: usize, T: PrimInt> SubAssign for PosN<D, T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const D: usize, T: PrimInt> Mul<T> for PosN<D, T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<const D: usize, T: PrimInt + Signed> Neg for PosN<D, T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn neighbors_diag() {
+        let centre = Pos3::new([1, -2, 3]);
+        let neighbors: Vec<_> = centre.neighbors_diag().collect();
+        let distinct: HashSet<_> = neighbors.iter().copied().collect();
+        assert_eq!(neighbors.len(), 26);
+        assert_eq!(distinct.len(), 26);
+        assert!(!distinct.contains(&centre));
+        assert!(neighbors.iter().all(|n| n.chebyshev(centre) == 1));
+
+        let centre = PosN::new([0, 0]);
+        let neighbors: HashSet<_> = centre.neighbors_diag().collect();
+        assert_eq!(neighbors.len(), 8);
+        assert!(!neighbors.contains(&centre));
+        assert!(neighbors.iter().all(|n| n.chebyshev(centre) == 1));
+    }
+
+    #[test]
+    fn neighbors_simple() {
+        let centre = Pos3::new([1, -2, 3]);
+        assert_eq!(
+            centre.neighbors_simple().collect::<Vec<_>>(),
+            [
+                Pos3::new([2, -2, 3]),
+                Pos3::new([0, -2, 3]),
+                Pos3::new([1, -1, 3]),
+                Pos3::new([1, -3, 3]),
+                Pos3::new([1, -2, 4]),
+                Pos3::new([1, -2, 2]),
+            ]
+        );
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Pos3::new([1, -2, 3]), Pos3::new([-3, 0, 4]));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(b.manhattan(a), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+        assert_eq!(a.chebyshev(a), 0);
+
+        let (a, b) = (Pos3::<u8>::new([5, 0, 2]), Pos3::new([1, 3, 2]));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+    }
+}
@@ -1,2 +1,11 @@
-pub use crate::map::{Map, MapSize};
+pub use crate::automaton::Automaton;
+pub use crate::dir::{Dir4, Dir8};
+pub use crate::fill::{Component, Components, Connectivity};
+pub use crate::grid::{FromChar, GridError};
+pub use crate::image::{Frames, Image, ImageFormat, Rgb};
+pub use crate::map::{Map, MapSize, Topology};
 pub use crate::pos::{Axis, Pos, EAST, NORTH, ORTHOGONAL, SOUTH, WEST};
+pub use crate::posn::{Pos3, PosN};
+pub use crate::render::Render;
+pub use crate::segment::{Segment, Slope};
+pub use crate::sparse::SparseMap;
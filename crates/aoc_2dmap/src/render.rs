@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter, Write};
+
+use crate::map::{Map, MapSize};
+use crate::pos::Pos;
+
+/// Text rendering of a map, one line per row, built with `Map::render`.
+pub struct Render<'a, T, F> {
+    map: &'a Map<T>,
+    tile: F,
+    separator: &'a str,
+    rulers: bool,
+    highlights: Vec<(HashSet<Pos>, char)>,
+    viewport: (Pos, MapSize),
+}
+
+impl<T> Map<T> {
+    /// Render the map with `tile` turning each tile into a character.
+    pub fn render<F: Fn(&T) -> char>(&self, tile: F) -> Render<'_, T, F> {
+        Render {
+            map: self,
+            tile,
+            separator: "",
+            rulers: false,
+            highlights: Vec::new(),
+            viewport: (Pos::default(), self.size),
+        }
+    }
+}
+
+impl<'a, T, F: Fn(&T) -> char> Render<'a, T, F> {
+    /// Put `separator` between the tiles of a row.
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Number the rows on the left and the columns on top, with the digits of
+    /// column numbers stacked vertically.
+    pub fn rulers(mut self) -> Self {
+        self.rulers = true;
+        self
+    }
+
+    /// Draw `with` instead of the tiles at `positions`, over any earlier
+    /// highlights.
+    pub fn highlight<I: IntoIterator<Item = Pos>>(mut self, positions: I, with: char) -> Self {
+        self.highlights
+            .push((positions.into_iter().collect(), with));
+        self
+    }
+
+    /// Only render the rectangle of `size` starting at `from`, or the part of
+    /// it that lies on the map.
+    pub fn viewport<P: Into<Pos>, S: Into<MapSize>>(mut self, from: P, size: S) -> Self {
+        self.viewport = self.map.clamp_rect(from.into(), size.into());
+        self
+    }
+
+    fn tile_char(&self, pos: Pos) -> char {
+        self.highlights
+            .iter()
+            .rev()
+            .find(|(positions, _)| positions.contains(&pos))
+            .map_or_else(|| (self.tile)(&self.map[pos]), |&(_, with)| with)
+    }
+
+    /// Write one line per character of the column numbers.
+    fn write_column_ruler(&self, f: &mut Formatter<'_>, margin: usize) -> std::fmt::Result {
+        let (from, size) = self.viewport;
+        let labels: Vec<_> = (from.x..from.x + size.x).map(|x| x.to_string()).collect();
+        let depth = labels.iter().map(String::len).max().unwrap_or(0);
+        for line in 0..depth {
+            write!(f, "{:margin$}", "")?;
+            for (idx, label) in labels.iter().enumerate() {
+                if idx > 0 {
+                    f.write_str(self.separator)?;
+                }
+                // right align the numbers, so that units share a line
+                let skip = depth - label.len();
+                let c = if line < skip {
+                    ' '
+                } else {
+                    label.as_bytes()[line - skip] as char
+                };
+                f.write_char(c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T, F: Fn(&T) -> char> Display for Render<'_, T, F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (from, size) = self.viewport;
+        let rows = from.y..from.y + size.y;
+        let label_width = if self.rulers {
+            let width = rows.clone().map(|y| y.to_string().len()).max().unwrap_or(0);
+            self.write_column_ruler(f, width + 1)?;
+            width
+        } else {
+            0
+        };
+
+        for (idx, y) in rows.enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            if self.rulers {
+                write!(f, "{:>label_width$} ", y)?;
+            }
+            for x in from.x..from.x + size.x {
+                if x > from.x {
+                    f.write_str(self.separator)?;
+                }
+                f.write_char(self.tile_char(Pos { x, y }))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Map<char> {
+        Map::from_grid(s).unwrap()
+    }
+
+    #[test]
+    fn plain() {
+        let map = grid("#..\n.#.");
+        assert_eq!(map.render(|&c| c).to_string(), "#..\n.#.");
+        assert_eq!(
+            map.render(|&c| if c == '#' { '█' } else { ' ' })
+                .separator(" ")
+                .to_string(),
+            "█    \n  █  "
+        );
+    }
+
+    #[test]
+    fn rulers() {
+        let map = grid("abcdefghijkl\nmnopqrstuvwx");
+        assert_eq!(
+            map.render(|&c| c).rulers().to_string(),
+            concat!(
+                "            11\n",
+                "  012345678901\n",
+                "0 abcdefghijkl\n",
+                "1 mnopqrstuvwx",
+            )
+        );
+    }
+
+    #[test]
+    fn highlight_and_viewport() {
+        let map = grid("abcd\nefgh\nijkl");
+        let render = || {
+            map.render(|&c| c)
+                .highlight([Pos::new(1, 1), Pos::new(2, 1)], '*')
+                .highlight([Pos::new(2, 1)], '@')
+        };
+        assert_eq!(render().to_string(), "abcd\ne*@h\nijkl");
+        assert_eq!(render().viewport((1, 0), (2, 5)).to_string(), "bc\n*@\njk");
+        assert_eq!(
+            render()
+                .viewport((1, 1), (2, 2))
+                .rulers()
+                .separator("|")
+                .to_string(),
+            concat!("  1|2\n", "1 *|@\n", "2 j|k")
+        );
+        assert_eq!(render().viewport((10, 10), (2, 2)).to_string(), "");
+    }
+}
@@ -0,0 +1,228 @@
+use std::collections::HashSet;
+use std::iter::from_fn;
+
+use crate::map::Map;
+use crate::pos::Pos;
+use crate::sparse::SparseMap;
+
+/// Straight line between two positions, both included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Segment {
+    pub from: Pos,
+    pub to: Pos,
+}
+
+/// Direction of a segment, as far as walking it on a grid is concerned.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Slope {
+    /// Both ends are the same position.
+    Point,
+    Horizontal,
+    Vertical,
+    /// 45°, moving as much along x as along y.
+    Diagonal,
+    /// Any other angle, only approximated by the positions it's drawn with.
+    Oblique,
+}
+
+impl Segment {
+    pub fn new(from: Pos, to: Pos) -> Self {
+        Self { from, to }
+    }
+
+    pub fn slope(&self) -> Slope {
+        let d = (self.to - self.from).abs();
+        match (d.x, d.y) {
+            (0, 0) => Slope::Point,
+            (_, 0) => Slope::Horizontal,
+            (0, _) => Slope::Vertical,
+            (x, y) if x == y => Slope::Diagonal,
+            _ => Slope::Oblique,
+        }
+    }
+
+    /// Horizontal, vertical or a single position.
+    pub fn is_axis_aligned(&self) -> bool {
+        matches!(
+            self.slope(),
+            Slope::Point | Slope::Horizontal | Slope::Vertical
+        )
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        self.slope() == Slope::Diagonal
+    }
+
+    /// Number of positions the segment is drawn with.
+    pub fn len(&self) -> usize {
+        self.from.chebyshev(self.to) as usize + 1
+    }
+
+    /// Never true, since a segment holds at least one position.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Positions from `from` to `to` drawn with Bresenham's algorithm, which
+    /// are exact for axis aligned and diagonal segments.
+    pub fn points(&self) -> impl Iterator<Item = Pos> {
+        let Segment { from, to } = *self;
+        let d = to - from;
+        let step = d.signum();
+        let (dx, dy) = (d.x.abs(), -d.y.abs());
+        let mut err = dx + dy;
+        let mut next = Some(from);
+        from_fn(move || {
+            let pos = next?;
+            next = (pos != to).then(|| {
+                let mut n = pos;
+                let e2 = 2 * err;
+                if e2 >= dy {
+                    err += dy;
+                    n.x += step.x;
+                }
+                if e2 <= dx {
+                    err += dx;
+                    n.y += step.y;
+                }
+                n
+            });
+            Some(pos)
+        })
+    }
+
+    /// Top left and bottom right corners of the rectangle around the segment.
+    pub fn bounds(&self) -> (Pos, Pos) {
+        (
+            self.from.component_min(self.to),
+            self.from.component_max(self.to),
+        )
+    }
+
+    /// Whether the two segments touch, treating them as continuous lines.
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let (a, b) = (self.from, self.to);
+        let (c, d) = (other.from, other.to);
+        let (o1, o2) = (orientation(a, b, c), orientation(a, b, d));
+        let (o3, o4) = (orientation(c, d, a), orientation(c, d, b));
+        if o1 * o2 < 0 && o3 * o4 < 0 {
+            return true;
+        }
+        (o1 == 0 && self.bounds_contain(c))
+            || (o2 == 0 && self.bounds_contain(d))
+            || (o3 == 0 && other.bounds_contain(a))
+            || (o4 == 0 && other.bounds_contain(b))
+    }
+
+    /// Positions both segments are drawn with, in the order of this one.
+    /// Oblique segments can cross between the positions they're drawn with,
+    /// so this can be empty even when `intersects` is true.
+    pub fn intersection(&self, other: &Segment) -> Vec<Pos> {
+        let (min, max) = self.bounds();
+        let (o_min, o_max) = other.bounds();
+        if min.x > o_max.x || o_min.x > max.x || min.y > o_max.y || o_min.y > max.y {
+            return Vec::new();
+        }
+        let theirs: HashSet<_> = other.points().collect();
+        self.points().filter(|p| theirs.contains(p)).collect()
+    }
+
+    fn bounds_contain(&self, pos: Pos) -> bool {
+        let (min, max) = self.bounds();
+        (min.x..=max.x).contains(&pos.x) && (min.y..=max.y).contains(&pos.y)
+    }
+}
+
+/// Sign of the turn from `a` to `b` to `c`: positive one way, negative the
+/// other, zero when they're in line.
+fn orientation(a: Pos, b: Pos, c: Pos) -> i64 {
+    let (ab, ac) = (b - a, c - a);
+    (ab.x as i64 * ac.y as i64 - ab.y as i64 * ac.x as i64).signum()
+}
+
+impl<T> Map<T> {
+    /// Call `f` on every tile `segment` is drawn over, skipping the positions
+    /// off the map.
+    pub fn draw<F: FnMut(&mut T)>(&mut self, segment: &Segment, mut f: F) {
+        for pos in segment.points() {
+            if let Some(pos) = self.resolve(pos) {
+                f(&mut self[pos]);
+            }
+        }
+    }
+}
+
+impl<T: Clone> SparseMap<T> {
+    /// Call `f` on every tile `segment` is drawn over, setting it to the
+    /// background first if needed.
+    pub fn draw<F: FnMut(&mut T)>(&mut self, segment: &Segment, mut f: F) {
+        for pos in segment.points() {
+            f(self.get_mut(pos));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg(from: (i32, i32), to: (i32, i32)) -> Segment {
+        Segment::new(from.into(), to.into())
+    }
+
+    fn positions(points: &[(i32, i32)]) -> Vec<Pos> {
+        points.iter().map(|&p| p.into()).collect()
+    }
+
+    #[test]
+    fn oblique_points() {
+        let s = seg((0, 0), (5, 2));
+        assert_eq!(s.slope(), Slope::Oblique);
+        assert_eq!(
+            s.points().collect::<Vec<_>>(),
+            positions(&[(0, 0), (1, 0), (2, 1), (3, 1), (4, 2), (5, 2)])
+        );
+        assert_eq!(s.len(), 6);
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(seg((3, 3), (3, 3)).len(), 1);
+        assert_eq!(seg((0, 0), (0, -4)).len(), 5);
+        assert_eq!(seg((2, 2), (-1, -1)).len(), 4);
+        assert_eq!(seg((2, 2), (-1, -1)).points().count(), 4);
+    }
+
+    #[test]
+    fn collinear_overlap() {
+        let (a, b) = (seg((0, 0), (4, 0)), seg((6, 0), (2, 0)));
+        assert!(a.intersects(&b));
+        assert_eq!(a.intersection(&b), positions(&[(2, 0), (3, 0), (4, 0)]));
+        assert_eq!(b.intersection(&a), positions(&[(4, 0), (3, 0), (2, 0)]));
+    }
+
+    #[test]
+    fn touching_endpoints() {
+        let (a, b) = (seg((0, 0), (2, 2)), seg((2, 2), (4, 0)));
+        assert!(a.intersects(&b));
+        assert_eq!(a.intersection(&b), positions(&[(2, 2)]));
+    }
+
+    #[test]
+    fn disjoint() {
+        let (a, b) = (seg((0, 0), (2, 0)), seg((0, 2), (2, 2)));
+        assert!(!a.intersects(&b));
+        assert!(a.intersection(&b).is_empty());
+
+        let (a, b) = (seg((0, 0), (1, 0)), seg((3, 0), (4, 0)));
+        assert!(!a.intersects(&b));
+        assert!(a.intersection(&b).is_empty());
+    }
+
+    #[test]
+    fn crossing_between_positions() {
+        let (a, b) = (seg((0, 0), (1, 2)), seg((1, 0), (0, 2)));
+        assert!(a.intersects(&b));
+        assert!(a.intersection(&b).is_empty());
+    }
+}
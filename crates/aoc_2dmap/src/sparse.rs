@@ -0,0 +1,368 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+use hashbrown::HashMap;
+
+use crate::map::Map;
+use crate::pos::Pos;
+
+/// Chunks are squares of `1 << CHUNK_BITS` tiles a side.
+const CHUNK_BITS: i32 = 5;
+const CHUNK_SIDE: i32 = 1 << CHUNK_BITS;
+const CHUNK_MASK: i32 = CHUNK_SIDE - 1;
+
+/// Unbounded map, negative coordinates included, that only stores the tiles
+/// which were set. Every other tile reads as the background.
+///
+/// Tiles are stored in square chunks, hashed by their position, so nearby
+/// tiles share a lookup and far apart ones cost no more than a chunk each.
+#[derive(Debug, Clone)]
+pub struct SparseMap<T> {
+    chunks: HashMap<Pos, Chunk<T>>,
+    len: usize,
+    bounds: Option<(Pos, Pos)>,
+    background: T,
+}
+
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    tiles: Box<[Option<T>]>,
+    len: usize,
+}
+
+impl<T> Chunk<T> {
+    fn new() -> Self {
+        Self {
+            tiles: (0..CHUNK_SIDE * CHUNK_SIDE).map(|_| None).collect(),
+            len: 0,
+        }
+    }
+}
+
+/// Position of the chunk holding `pos`, and the index of `pos` within it.
+fn split(pos: Pos) -> (Pos, usize) {
+    let chunk = Pos::new(pos.x >> CHUNK_BITS, pos.y >> CHUNK_BITS);
+    let idx = (pos.y & CHUNK_MASK) * CHUNK_SIDE + (pos.x & CHUNK_MASK);
+    (chunk, idx as usize)
+}
+
+/// Inverse of `split`.
+fn join(chunk: Pos, idx: usize) -> Pos {
+    let idx = idx as i32;
+    Pos::new(
+        chunk.x << CHUNK_BITS | idx & CHUNK_MASK,
+        chunk.y << CHUNK_BITS | idx >> CHUNK_BITS,
+    )
+}
+
+impl<T> SparseMap<T> {
+    pub fn new(background: T) -> Self {
+        Self {
+            chunks: HashMap::new(),
+            len: 0,
+            bounds: None,
+            background,
+        }
+    }
+
+    /// Take over every tile of a dense map, keeping its positions.
+    pub fn from_map(map: Map<T>, background: T) -> Self {
+        let positions: Vec<_> = map.size.iter().collect();
+        let mut sparse = Self::new(background);
+        for (pos, tile) in positions.into_iter().zip(map.into_tiles()) {
+            sparse.set(pos, tile);
+        }
+        sparse
+    }
+
+    pub fn background(&self) -> &T {
+        &self.background
+    }
+
+    pub fn set_background(&mut self, background: T) {
+        self.background = background;
+    }
+
+    pub fn get<P: AsRef<Pos>>(&self, pos: P) -> T
+    where
+        T: Clone,
+    {
+        self.get_ref(pos).clone()
+    }
+
+    pub fn get_ref<P: AsRef<Pos>>(&self, pos: P) -> &T {
+        let (chunk, idx) = split(*pos.as_ref());
+        self.chunks
+            .get(&chunk)
+            .and_then(|chunk| chunk.tiles[idx].as_ref())
+            .unwrap_or(&self.background)
+    }
+
+    /// Mutable tile at `pos`, which is set to the background first if needed.
+    pub fn get_mut<P: AsRef<Pos>>(&mut self, pos: P) -> &mut T
+    where
+        T: Clone,
+    {
+        let pos = *pos.as_ref();
+        if !self.contains(pos) {
+            self.set(pos, self.background.clone());
+        }
+        let (chunk, idx) = split(pos);
+        self.chunks.get_mut(&chunk).unwrap().tiles[idx]
+            .as_mut()
+            .unwrap()
+    }
+
+    pub fn set<P: AsRef<Pos>>(&mut self, pos: P, tile: T) {
+        let pos = *pos.as_ref();
+        let (chunk, idx) = split(pos);
+        let chunk = self.chunks.entry(chunk).or_insert_with(Chunk::new);
+        if chunk.tiles[idx].replace(tile).is_none() {
+            chunk.len += 1;
+            self.len += 1;
+            self.bounds = Some(match self.bounds {
+                Some((min, max)) => (min.component_min(pos), max.component_max(pos)),
+                None => (pos, pos),
+            });
+        }
+    }
+
+    /// Reset the tile at `pos` to the background, returning what was set.
+    pub fn remove<P: AsRef<Pos>>(&mut self, pos: P) -> Option<T> {
+        let pos = *pos.as_ref();
+        let (key, idx) = split(pos);
+        let chunk = self.chunks.get_mut(&key)?;
+        let removed = chunk.tiles[idx].take()?;
+        chunk.len -= 1;
+        if chunk.len == 0 {
+            self.chunks.remove(&key);
+        }
+        self.len -= 1;
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y {
+                self.bounds = self.find_bounds();
+            }
+        }
+        Some(removed)
+    }
+
+    pub fn contains<P: AsRef<Pos>>(&self, pos: P) -> bool {
+        let (chunk, idx) = split(*pos.as_ref());
+        self.chunks
+            .get(&chunk)
+            .is_some_and(|chunk| chunk.tiles[idx].is_some())
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Positions of the tiles that were set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.tiles().map(|(pos, _)| pos)
+    }
+
+    /// Tiles that were set along with their positions, in no particular order.
+    pub fn tiles(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.chunks.iter().flat_map(|(&key, chunk)| {
+            chunk
+                .tiles
+                .iter()
+                .enumerate()
+                .filter_map(move |(idx, tile)| Some((join(key, idx), tile.as_ref()?)))
+        })
+    }
+
+    /// The square of tiles `radius` away from `center` at most, in reading order.
+    pub fn window(&self, center: Pos, radius: i32) -> impl Iterator<Item = &T> {
+        // Neighbouring tiles mostly share a chunk, so only look it up again
+        // when moving into another one.
+        let mut last: Option<(Pos, Option<&Chunk<T>>)> = None;
+        center.window(radius).map(move |pos| {
+            let (key, idx) = split(pos);
+            let chunk = match last {
+                Some((last_key, chunk)) if last_key == key => chunk,
+                _ => last.insert((key, self.chunks.get(&key))).1,
+            };
+            chunk
+                .and_then(|chunk| chunk.tiles[idx].as_ref())
+                .unwrap_or(&self.background)
+        })
+    }
+
+    /// Top left and bottom right corners (both inclusive) of the smallest
+    /// rectangle holding every tile that was set.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.bounds
+    }
+
+    fn find_bounds(&self) -> Option<(Pos, Pos)> {
+        let mut positions = self.iter();
+        let first = positions.next()?;
+        Some(positions.fold((first, first), |(min, max), p| {
+            (min.component_min(p), max.component_max(p))
+        }))
+    }
+}
+
+/// Maps are equal when they have the same background and set the same tiles.
+impl<T: PartialEq> PartialEq for SparseMap<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.background == other.background
+            && self.len == other.len
+            && self
+                .tiles()
+                .all(|(pos, tile)| other.contains(pos) && other.get_ref(pos) == tile)
+    }
+}
+
+impl<T: Eq> Eq for SparseMap<T> {}
+
+/// Empty map with the default tile as background.
+impl<T: Default> Default for SparseMap<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T, P: AsRef<Pos>> Index<P> for SparseMap<T> {
+    type Output = T;
+
+    fn index(&self, index: P) -> &Self::Output {
+        self.get_ref(index)
+    }
+}
+
+impl<T: Clone, P: AsRef<Pos>> IndexMut<P> for SparseMap<T> {
+    fn index_mut(&mut self, index: P) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+/// Renders the bounding box, like `Map` does.
+impl<T: Display> Display for SparseMap<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some((min, max)) = self.bounds() {
+            for y in min.y..=max.y {
+                writeln!(f)?;
+                for x in min.x..=max.x {
+                    write!(f, "{}", self.get_ref(Pos { x, y }))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_and_get() {
+        let mut map = SparseMap::new('.');
+        map.set(Pos::new(1, 2), '#');
+        assert_eq!(map.get(Pos::new(1, 2)), '#');
+        assert_eq!(map[Pos::new(2, 1)], '.');
+        assert!(map.contains(Pos::new(1, 2)));
+        assert!(!map.contains(Pos::new(2, 1)));
+        assert_eq!(map.len(), 1);
+
+        map.set(Pos::new(1, 2), 'o');
+        assert_eq!(map.get(Pos::new(1, 2)), 'o');
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn background() {
+        let mut map = SparseMap::new(0);
+        *map.get_mut(Pos::new(3, 3)) += 2;
+        map[Pos::new(3, 3)] += 1;
+        assert_eq!(map.get(Pos::new(3, 3)), 3);
+        assert_eq!(map.len(), 1);
+
+        map.set_background(7);
+        assert_eq!(map.get(Pos::new(0, 0)), 7);
+        assert_eq!(map.get(Pos::new(3, 3)), 3);
+        assert_eq!(map.remove(Pos::new(3, 3)), Some(3));
+        assert_eq!(map.remove(Pos::new(3, 3)), None);
+        assert_eq!(map.get(Pos::new(3, 3)), 7);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let mut map = SparseMap::new('.');
+        for (i, pos) in [(-1, -1), (-16, 0), (-17, -33), (0, -1)]
+            .into_iter()
+            .enumerate()
+        {
+            map.set(Pos::from(pos), char::from(b'a' + i as u8));
+        }
+        assert_eq!(map.get(Pos::new(-1, -1)), 'a');
+        assert_eq!(map.get(Pos::new(-16, 0)), 'b');
+        assert_eq!(map.get(Pos::new(-17, -33)), 'c');
+        assert_eq!(map.get(Pos::new(0, -1)), 'd');
+        assert_eq!(map.get(Pos::new(-1, 0)), '.');
+
+        let mut set: Vec<_> = map.iter().collect();
+        set.sort_by_key(|p| (p.y, p.x));
+        assert_eq!(
+            set,
+            [
+                Pos::new(-17, -33),
+                Pos::new(-1, -1),
+                Pos::new(0, -1),
+                Pos::new(-16, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        let mut map = SparseMap::new('.');
+        assert_eq!(map.bounds(), None);
+        map.set(Pos::new(2, -3), '#');
+        assert_eq!(map.bounds(), Some((Pos::new(2, -3), Pos::new(2, -3))));
+        map.set(Pos::new(-4, 5), '#');
+        map.set(Pos::new(0, 0), '#');
+        assert_eq!(map.bounds(), Some((Pos::new(-4, -3), Pos::new(2, 5))));
+
+        map.remove(Pos::new(0, 0));
+        assert_eq!(map.bounds(), Some((Pos::new(-4, -3), Pos::new(2, 5))));
+        map.remove(Pos::new(-4, 5));
+        assert_eq!(map.bounds(), Some((Pos::new(2, -3), Pos::new(2, -3))));
+        map.remove(Pos::new(2, -3));
+        assert_eq!(map.bounds(), None);
+    }
+
+    #[test]
+    fn distant_tiles() {
+        let mut map = SparseMap::new(0u8);
+        map.set(Pos::new(0, 0), 1);
+        map.set(Pos::new(50_000, 50_000), 2);
+        map.set(Pos::new(i32::MIN, i32::MAX), 3);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.chunks.len(), 3);
+        assert_eq!(map.get(Pos::new(50_000, 50_000)), 2);
+        assert_eq!(map.get(Pos::new(i32::MIN, i32::MAX)), 3);
+        assert_eq!(
+            map.bounds(),
+            Some((Pos::new(i32::MIN, 0), Pos::new(50_000, i32::MAX)))
+        );
+    }
+
+    #[test]
+    fn from_map() {
+        let dense: Map<char> = Map::from_grid("#.\n.#").unwrap();
+        let map = SparseMap::from_map(dense, ' ');
+        assert_eq!(map.len(), 4);
+        assert_eq!(map.get(Pos::new(1, 1)), '#');
+        assert_eq!(map.get(Pos::new(2, 1)), ' ');
+        assert_eq!(map.to_string(), "\n#.\n.#");
+    }
+}
@@ -0,0 +1,187 @@
+use crate::map::{Map, MapSize};
+use crate::pos::{Axis, Pos};
+
+impl<T: Clone> Map<T> {
+    /// Build a map of `size` where each tile is copied from `from(pos)`,
+    /// which must lie on this map. The topology is kept as is.
+    fn remap<F: Fn(Pos) -> Pos>(&self, size: MapSize, from: F) -> Self {
+        Map::new(size, size.iter().map(|pos| self.get_unchecked(from(pos))))
+            .with_topology(self.topology())
+    }
+
+    /// Rotate by `quarter_turns` times 90° clockwise; negative turns go
+    /// anticlockwise. A map wrapping along one axis wraps along the other
+    /// after a quarter turn.
+    pub fn rotate(&self, quarter_turns: i32) -> Self {
+        let Pos { x: w, y: h } = self.size;
+        let transposed = self.topology().transposed();
+        match quarter_turns.rem_euclid(4) {
+            1 => self
+                .remap(Pos::new(h, w), |p| Pos::new(p.y, h - 1 - p.x))
+                .with_topology(transposed),
+            2 => self.remap(self.size, |p| Pos::new(w - 1 - p.x, h - 1 - p.y)),
+            3 => self
+                .remap(Pos::new(h, w), |p| Pos::new(w - 1 - p.y, p.x))
+                .with_topology(transposed),
+            _ => self.clone(),
+        }
+    }
+
+    /// Mirror the map by reversing its x (`Axis::X`) or y (`Axis::Y`) coordinates.
+    pub fn flip(&self, along: Axis) -> Self {
+        let Pos { x: w, y: h } = self.size;
+        match along {
+            Axis::X => self.remap(self.size, |p| Pos::new(w - 1 - p.x, p.y)),
+            Axis::Y => self.remap(self.size, |p| Pos::new(p.x, h - 1 - p.y)),
+        }
+    }
+
+    /// Swap the x and y axes, topology included.
+    pub fn transpose(&self) -> Self {
+        self.remap(Pos::new(self.size.y, self.size.x), |p| Pos::new(p.y, p.x))
+            .with_topology(self.topology().transposed())
+    }
+
+    /// Copy of the rectangle of `size` starting at `from`, or of the part of
+    /// it that lies on the map.
+    pub fn crop<P: Into<Pos>, S: Into<MapSize>>(&self, from: P, size: S) -> Self {
+        let (from, size) = self.clamp_rect(from.into(), size.into());
+        self.remap(size, |p| p + from)
+    }
+
+    /// Append `other` after this map along `along`, keeping this map's
+    /// topology. Both maps must have the same size across it.
+    pub fn concat(&self, other: &Self, along: Axis) -> Self {
+        let size = along.map(self.size.x, self.size.y);
+        let other_size = along.map(other.size.x, other.size.y);
+        assert_eq!(
+            size.y, other_size.y,
+            "maps differ in size across {:?}",
+            along
+        );
+
+        let offset = along.map(size.x, 0);
+        let concat_size = along.map(size.x + other_size.x, size.y);
+        Map::new(
+            concat_size,
+            concat_size.iter().map(|pos| match self.within(pos) {
+                true => self.get_unchecked(pos),
+                false => other.get_unchecked(pos - offset),
+            }),
+        )
+        .with_topology(self.topology())
+    }
+
+    /// Repeat the map `across` times horizontally and `down` times vertically,
+    /// mapping each tile with `f(tile, tile_pos)`, where `tile_pos` is the
+    /// position of the copy it's in.
+    pub fn tile<F: Fn(&T, Pos) -> T>(&self, across: i32, down: i32, f: F) -> Self {
+        let Pos { x: w, y: h } = self.size;
+        let size = Pos::new(w * across, h * down);
+        Map::new(
+            size,
+            size.iter().map(|p| {
+                let tile = self.get_unchecked_ref(Pos::new(p.x % w, p.y % h));
+                f(tile, Pos::new(p.x / w, p.y / h))
+            }),
+        )
+        .with_topology(self.topology())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::Topology;
+
+    fn grid(s: &str) -> Map<char> {
+        Map::from_grid(s).unwrap()
+    }
+
+    fn rows(map: &Map<char>) -> Vec<String> {
+        (0..map.size.y).map(|y| map.get_row(y).collect()).collect()
+    }
+
+    #[test]
+    fn rotate() {
+        let map = grid("abc\ndef");
+        assert_eq!(rows(&map.rotate(1)), ["da", "eb", "fc"]);
+        assert_eq!(rows(&map.rotate(2)), ["fed", "cba"]);
+        assert_eq!(rows(&map.rotate(3)), ["cf", "be", "ad"]);
+        assert_eq!(rows(&map.rotate(-1)), ["cf", "be", "ad"]);
+        assert_eq!(rows(&map.rotate(4)), ["abc", "def"]);
+        assert_eq!(map.rotate(1).size, Pos::new(2, 3));
+    }
+
+    #[test]
+    fn flip() {
+        let map = grid("abc\ndef");
+        assert_eq!(rows(&map.flip(Axis::X)), ["cba", "fed"]);
+        assert_eq!(rows(&map.flip(Axis::Y)), ["def", "abc"]);
+    }
+
+    #[test]
+    fn transpose() {
+        let map = grid("abc\ndef");
+        assert_eq!(rows(&map.transpose()), ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn crop() {
+        let map = grid("abc\ndef\nghi");
+        assert_eq!(rows(&map.crop((1, 0), (2, 2))), ["bc", "ef"]);
+        assert_eq!(rows(&map.crop((1, 1), (5, 5))), ["ef", "hi"]);
+        assert_eq!(map.crop((3, 0), (2, 2)).size, Pos::new(0, 2));
+    }
+
+    #[test]
+    fn concat() {
+        let map = grid("abc\ndef");
+        assert_eq!(
+            rows(&map.concat(&grid("gh\nij"), Axis::X)),
+            ["abcgh", "defij"]
+        );
+        assert_eq!(
+            rows(&map.concat(&grid("ghi"), Axis::Y)),
+            ["abc", "def", "ghi"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "maps differ in size across X")]
+    fn concat_mismatched() {
+        grid("abc\ndef").concat(&grid("gh"), Axis::X);
+    }
+
+    #[test]
+    fn tile() {
+        let map = grid("ab");
+        let tiled = map.tile(2, 3, |&c, copy| match (copy.x + copy.y) % 2 {
+            0 => c,
+            _ => c.to_ascii_uppercase(),
+        });
+        assert_eq!(rows(&tiled), ["abAB", "ABab", "abAB"]);
+    }
+
+    #[test]
+    fn wrapped() {
+        let map = grid("ab\ncd").with_topology(Topology::WrapX);
+        let joined = map.concat(&grid("xy\nzw"), Axis::X);
+        assert_eq!(rows(&joined), ["abxy", "cdzw"]);
+        assert_eq!(joined.topology(), Topology::WrapX);
+        let stacked = map.concat(&grid("xy"), Axis::Y);
+        assert_eq!(rows(&stacked), ["ab", "cd", "xy"]);
+
+        assert_eq!(map.rotate(1).topology(), Topology::WrapY);
+        assert_eq!(map.rotate(2).topology(), Topology::WrapX);
+        assert_eq!(map.rotate(-1).topology(), Topology::WrapY);
+        assert_eq!(map.transpose().topology(), Topology::WrapY);
+        assert_eq!(map.flip(Axis::Y).topology(), Topology::WrapX);
+        assert_eq!(map.crop((0, 0), (1, 2)).topology(), Topology::WrapX);
+        assert_eq!(map.tile(2, 1, |&c, _| c).topology(), Topology::WrapX);
+
+        let torus = map.with_topology(Topology::Torus);
+        assert_eq!(torus.rotate(1).topology(), Topology::Torus);
+        assert_eq!(rows(&torus.rotate(1)), ["ca", "db"]);
+    }
+}
@@ -0,0 +1,150 @@
+use crate::map::Map;
+use crate::pos::Pos;
+
+impl<T> Map<T> {
+    /// Tiles of row `y`, left to right.
+    pub fn row_slice(&self, y: i32) -> &[T] {
+        assert!((0..self.size.y).contains(&y), "row {} is off the map", y);
+        let width = self.size.x as usize;
+        &self.get_tiles()[y as usize * width..][..width]
+    }
+
+    pub fn row_slice_mut(&mut self, y: i32) -> &mut [T] {
+        assert!((0..self.size.y).contains(&y), "row {} is off the map", y);
+        let width = self.size.x as usize;
+        &mut self.get_tiles_mut()[y as usize * width..][..width]
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        let width = (self.size.x as usize).max(1);
+        self.get_tiles().chunks(width)
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let width = (self.size.x as usize).max(1);
+        self.get_tiles_mut().chunks_mut(width)
+    }
+
+    /// Tiles of column `x`, top to bottom.
+    pub fn col(&self, x: i32) -> impl Iterator<Item = &T> {
+        assert!((0..self.size.x).contains(&x), "column {} is off the map", x);
+        let width = self.size.x as usize;
+        self.get_tiles()[x as usize..].iter().step_by(width)
+    }
+
+    pub fn col_mut(&mut self, x: i32) -> impl Iterator<Item = &mut T> {
+        assert!((0..self.size.x).contains(&x), "column {} is off the map", x);
+        let width = self.size.x as usize;
+        self.get_tiles_mut()[x as usize..].iter_mut().step_by(width)
+    }
+
+    /// Every column, left to right.
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.size.x).map(move |x| self.col(x))
+    }
+
+    /// The square of tiles `radius` away from `center` at most, in reading
+    /// order, with `None` for those off the map.
+    pub fn window(&self, center: Pos, radius: i32) -> impl Iterator<Item = Option<&T>> {
+        center.window(radius).map(move |pos| self.get_ref(pos))
+    }
+
+    /// A `window` around every tile of the map, in reading order.
+    pub fn windows(
+        &self,
+        radius: i32,
+    ) -> impl Iterator<Item = (Pos, impl Iterator<Item = Option<&T>>)> {
+        self.iter().map(move |pos| (pos, self.window(pos, radius)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(s: &str) -> Map<char> {
+        Map::from_grid(s).unwrap()
+    }
+
+    #[test]
+    fn rows() {
+        let map = grid("abc\ndef");
+        assert_eq!(map.row_slice(0), ['a', 'b', 'c']);
+        assert_eq!(map.row_slice(1), ['d', 'e', 'f']);
+        let rows: Vec<String> = map.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, ["abc", "def"]);
+    }
+
+    #[test]
+    fn rows_mut() {
+        let mut map = grid("abc\ndef");
+        map.row_slice_mut(1).reverse();
+        for row in map.rows_mut() {
+            row[0] = '#';
+        }
+        assert_eq!(map.row_slice(0), ['#', 'b', 'c']);
+        assert_eq!(map.row_slice(1), ['#', 'e', 'd']);
+    }
+
+    #[test]
+    #[should_panic(expected = "row -1 is off the map")]
+    fn negative_row() {
+        grid("abc\ndef").row_slice(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 2 is off the map")]
+    fn row_past_the_end() {
+        grid("abc\ndef").row_slice_mut(2);
+    }
+
+    #[test]
+    fn cols() {
+        let map = grid("abc\ndef");
+        assert_eq!(map.col(1).collect::<String>(), "be");
+        let cols: Vec<String> = map.cols().map(|col| col.collect()).collect();
+        assert_eq!(cols, ["ad", "be", "cf"]);
+    }
+
+    #[test]
+    fn col_mut() {
+        let mut map = grid("abc\ndef");
+        for tile in map.col_mut(2) {
+            *tile = tile.to_ascii_uppercase();
+        }
+        assert_eq!(
+            map.rows()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<_>>(),
+            ["abC", "deF"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "column 3 is off the map")]
+    fn col_past_the_end() {
+        let _ = grid("abc\ndef").col(3);
+    }
+
+    #[test]
+    fn window() {
+        let map = grid("abc\ndef");
+        let window: Vec<_> = map.window(Pos::new(0, 0), 1).collect();
+        assert_eq!(
+            window,
+            [
+                None,
+                None,
+                None,
+                None,
+                Some(&'a'),
+                Some(&'b'),
+                None,
+                Some(&'d'),
+                Some(&'e')
+            ]
+        );
+        assert_eq!(map.windows(1).count(), 6);
+    }
+}
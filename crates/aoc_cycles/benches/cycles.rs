@@ -0,0 +1,18 @@
+use aoc_cycles::multicycle;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+fn multicycle_rotate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multicycle");
+    // Rotating `len` elements comes back around after `len` cycles.
+    let rotate = |v: &mut Vec<u32>| v.rotate_left(1);
+    for len in [16, 256, 1024] {
+        let start: Vec<u32> = (0..len).collect();
+        group.bench_with_input(BenchmarkId::new("rotate", len), &start, |b, start| {
+            b.iter(|| multicycle(black_box(start.clone()), rotate, 1_000_000_000))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, multicycle_rotate);
+criterion_main!(benches);
@@ -0,0 +1,75 @@
+use aoc_2dmap::prelude::*;
+use aoc_dijsktra::{Dijsktra, GameState, Transform};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[derive(PartialOrd, Ord, PartialEq, Eq, Hash)]
+struct State {
+    pos: Pos,
+    goal: Pos,
+}
+
+struct Move {
+    to: Pos,
+    cost: usize,
+}
+
+impl GameState<Map<usize>> for State {
+    type Steps = Vec<Move>;
+
+    fn accept(&self, _cost: usize, _ctx: &mut Map<usize>) -> bool {
+        self.pos == self.goal
+    }
+
+    fn steps(&self, map: &mut Map<usize>) -> Self::Steps {
+        self.pos
+            .neighbors_simple()
+            .filter_map(|to| map.get(to).map(|cost| Move { to, cost }))
+            .collect()
+    }
+}
+
+impl Transform<State> for Move {
+    fn cost(&self) -> usize {
+        self.cost
+    }
+
+    fn transform(&self, state: &State) -> State {
+        State {
+            pos: self.to,
+            goal: state.goal,
+        }
+    }
+}
+
+/// Square grid of pseudo-random costs in 1..=9, like a day 15 risk map.
+fn grid(size: i32) -> Map<usize> {
+    let mut seed = 0x2545_f491_u32;
+    let costs = (0..size * size).map(move |_| {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (seed % 9 + 1) as usize
+    });
+    Map::new((size, size), costs)
+}
+
+fn shortest_path(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dijsktra");
+    group.sample_size(20);
+    for size in [50, 100, 200] {
+        let map = grid(size);
+        group.bench_with_input(BenchmarkId::new("grid", size), &map, |b, map| {
+            b.iter(|| {
+                State {
+                    pos: Pos::default(),
+                    goal: (size - 1, size - 1).into(),
+                }
+                .dijsktra(&mut map.clone())
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, shortest_path);
+criterion_main!(benches);
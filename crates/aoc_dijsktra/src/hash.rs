@@ -20,7 +20,7 @@ pub(crate) struct KeyMap<N: PrimInt> {
 }
 
 impl<N: PrimInt> KeyMap<N> {
-    pub fn entry<H: Hash>(&mut self, k: &H) -> Entry<u64, N, MirrorHashBuilder> {
+    pub fn entry<H: Hash>(&mut self, k: &H) -> Entry<'_, u64, N, MirrorHashBuilder> {
         self.hashmap.entry(manually_hash(k))
     }
 }
@@ -26,8 +26,29 @@ pub trait GameState<C, N = usize>: Ord + Hash {
     fn steps(&self, ctx: &mut C) -> Self::Steps;
 }
 
-pub trait Dijsktra<C, N>: private::Sealed<C, N> {
+/// Least costly way of reaching a goal, as found by `dijsktra_path`.
+pub struct Path<G, S, N = usize> {
+    pub cost: N,
+    /// Every state from the start to the goal, both included.
+    pub states: Vec<G>,
+    /// The transforms taken, `steps[i]` leading from `states[i]` to `states[i + 1]`.
+    pub steps: Vec<S>,
+}
+
+pub trait Dijsktra<C, N>: private::Sealed<C, N> + Sized {
+    /// The `Transform`s this game state produces.
+    type Step;
+
     fn dijsktra(self, ctx: &mut C) -> Option<N>;
+
+    fn dijsktra_path(self, ctx: &mut C) -> Option<Path<Self, Self::Step, N>>;
+}
+
+/// A state reached during a `dijsktra_path` search, along with the index of
+/// the node and the step it was reached from.
+struct Node<G, S> {
+    state: G,
+    from: Option<(usize, S)>,
 }
 
 /// `GameState` implementors who produce self-compatible `Transform`s (through
@@ -41,6 +62,8 @@ where
     T: GameState<C, N>,
     <T::Steps as IntoIterator>::Item: Transform<T, N>,
 {
+    type Step = <T::Steps as IntoIterator>::Item;
+
     /// Compute the least total cost for reaching a goal (as indicated by
     /// the `accept` method on the `GameState` implementor).
     fn dijsktra(self, context: &mut C) -> Option<N> {
@@ -74,6 +97,75 @@ where
         }
         None
     }
+
+    /// Like `dijsktra`, but also return the states and steps leading to the
+    /// goal. Every state reached is kept around until the search ends, so
+    /// this is slower and hungrier than plain `dijsktra`.
+    fn dijsktra_path(self, context: &mut C) -> Option<Path<T, Self::Step, N>> {
+        let mut known = hash::KeyMap::default();
+        let mut pq = BinaryHeap::with_capacity(1024);
+        let mut nodes = vec![Node {
+            state: self,
+            from: None,
+        }];
+
+        pq.push((Reverse(N::from(0).unwrap()), 0));
+
+        while let Some((Reverse(cost), idx)) = pq.pop() {
+            let state = &nodes[idx].state;
+            if state.accept(cost, context) {
+                return Some(backtrack(nodes, idx, cost));
+            }
+            for step in state.steps(context) {
+                let new_cost = cost + step.cost();
+                let new_state = step.transform(&nodes[idx].state);
+
+                let better = match known.entry(&new_state) {
+                    Entry::Occupied(mut entry) if new_cost < *entry.get() => {
+                        entry.insert(new_cost);
+                        true
+                    }
+                    Entry::Vacant(entry) => {
+                        entry.insert(new_cost);
+                        true
+                    }
+                    _ => false,
+                };
+                if better {
+                    pq.push((Reverse(new_cost), nodes.len()));
+                    nodes.push(Node {
+                        state: new_state,
+                        from: Some((idx, step)),
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Follow the nodes back from the one at `goal` to the start.
+fn backtrack<G, S, N>(nodes: Vec<Node<G, S>>, goal: usize, cost: N) -> Path<G, S, N> {
+    let mut on_path = vec![false; nodes.len()];
+    let mut idx = goal;
+    on_path[idx] = true;
+    while let Some((from, _)) = nodes[idx].from {
+        on_path[from] = true;
+        idx = from;
+    }
+
+    let mut path = Path {
+        cost,
+        states: Vec::new(),
+        steps: Vec::new(),
+    };
+    // parents are always pushed before their children, so the nodes on the
+    // path already are in order
+    for (node, _) in nodes.into_iter().zip(on_path).filter(|(_, on)| *on) {
+        path.states.push(node.state);
+        path.steps.extend(node.from.map(|(_, step)| step));
+    }
+    path
 }
 
 /// Prevent other crates from implementing the `Dijsktra` trait. 😈
@@ -90,3 +182,100 @@ mod private {
     {
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Directed edges as `(from, to, cost)`, and the node to reach.
+    struct Graph {
+        edges: Vec<(u8, u8, usize)>,
+        goal: u8,
+    }
+
+    #[derive(Debug, Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Hash)]
+    struct Node(u8);
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+    struct Edge {
+        to: u8,
+        cost: usize,
+    }
+
+    impl GameState<Graph> for Node {
+        type Steps = Vec<Edge>;
+
+        fn accept(&self, _cost: usize, graph: &mut Graph) -> bool {
+            self.0 == graph.goal
+        }
+
+        fn steps(&self, graph: &mut Graph) -> Self::Steps {
+            graph
+                .edges
+                .iter()
+                .filter(|&&(from, _, _)| from == self.0)
+                .map(|&(_, to, cost)| Edge { to, cost })
+                .collect()
+        }
+    }
+
+    impl Transform<Node> for Edge {
+        fn cost(&self) -> usize {
+            self.cost
+        }
+
+        fn transform(&self, _state: &Node) -> Node {
+            Node(self.to)
+        }
+    }
+
+    /// Two routes of cost 2 from 0 to 3, through 1 or 2, and a costlier
+    /// direct edge. 4 has a way out but no way in.
+    fn diamond(goal: u8) -> Graph {
+        Graph {
+            edges: vec![
+                (0, 3, 3),
+                (0, 1, 1),
+                (0, 2, 1),
+                (1, 3, 1),
+                (2, 3, 1),
+                (4, 0, 1),
+            ],
+            goal,
+        }
+    }
+
+    #[test]
+    fn unreachable() {
+        assert_eq!(Node(0).dijsktra(&mut diamond(4)), None);
+        assert!(Node(0).dijsktra_path(&mut diamond(4)).is_none());
+        assert!(Node(3).dijsktra_path(&mut diamond(0)).is_none());
+    }
+
+    #[test]
+    fn start_is_goal() {
+        assert_eq!(Node(2).dijsktra(&mut diamond(2)), Some(0));
+        let path = Node(2).dijsktra_path(&mut diamond(2)).unwrap();
+        assert_eq!(path.cost, 0);
+        assert_eq!(path.states, [Node(2)]);
+        assert!(path.steps.is_empty());
+    }
+
+    #[test]
+    fn tie_broken_path() {
+        assert_eq!(Node(4).dijsktra(&mut diamond(3)), Some(3));
+        let path = Node(4).dijsktra_path(&mut diamond(3)).unwrap();
+        assert_eq!(path.cost, 3);
+        // of the states at the same cost, the last one reached is expanded
+        // first, and the first way found to a state is kept on a tie
+        assert_eq!(path.states, [Node(4), Node(0), Node(2), Node(3)]);
+        assert_eq!(
+            path.steps,
+            [
+                Edge { to: 0, cost: 1 },
+                Edge { to: 2, cost: 1 },
+                Edge { to: 3, cost: 1 }
+            ]
+        );
+    }
+}